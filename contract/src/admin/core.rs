@@ -0,0 +1,52 @@
+use crate::errors::RafflerError;
+use crate::utils::assert_condition;
+use crate::{Contract, ContractExt};
+use near_sdk::{env, near_bindgen, AccountId};
+
+#[near_bindgen]
+impl Contract {
+    pub(crate) fn assert_admin(&self) {
+        assert_condition(
+            self.admin_id == env::predecessor_account_id(),
+            RafflerError::NotContractAdmin,
+        );
+    }
+
+    pub(crate) fn assert_not_paused(&self) {
+        assert_condition(!self.paused, RafflerError::ContractPaused);
+    }
+
+    pub(crate) fn assert_creator_allowed(&self, account_id: &AccountId) {
+        assert_condition(
+            !self.config.only_verified_creators || self.internal_is_verified_creator(account_id),
+            RafflerError::NotVerifiedCreator,
+        );
+    }
+
+    pub(crate) fn internal_is_verified_creator(&self, account_id: &AccountId) -> bool {
+        self.verified_creators.get(account_id).is_some()
+    }
+
+    pub(crate) fn assert_not_blocked(&self, account_id: &AccountId) {
+        assert_condition(
+            !self.internal_is_blocked(account_id),
+            RafflerError::AccountBlocked {
+                account_id: account_id.clone(),
+            },
+        );
+    }
+
+    // account is blocked by itself or as a subaccount of any of its parents
+    pub(crate) fn internal_is_blocked(&self, account_id: &AccountId) -> bool {
+        if self.blocked_accounts.contains_key(account_id) {
+            return true;
+        }
+
+        let account_id = account_id.as_str();
+
+        account_id
+            .match_indices('.')
+            .filter_map(|(index, _)| account_id[index + 1..].parse::<AccountId>().ok())
+            .any(|parent_id| self.blocked_subaccounts.contains_key(&parent_id))
+    }
+}
@@ -0,0 +1,816 @@
+use near_sdk::json_types::U128;
+use near_sdk::{env, is_promise_success, AccountId, Gas, Promise};
+
+use super::types::{BlocklistEntry, Config, CreatorProfile, MAX_DISPLAY_NAME_LENGTH, MAX_FEE_BPS};
+use crate::errors::RafflerError;
+use crate::utils::*;
+use crate::*;
+
+use witgen::witgen;
+
+const GAS_FOR_RESOLVE_WITHDRAW_FEES: Gas = Gas(5_000_000_000_000);
+
+#[near_bindgen]
+impl Contract {
+    #[witgen]
+    #[payable]
+    pub fn set_admin(&mut self, admin_id: AccountId) {
+        assert_exactly_one_yocto();
+
+        self.assert_admin();
+
+        self.admin_id = admin_id;
+    }
+
+    #[witgen]
+    #[payable]
+    pub fn pause(&mut self) {
+        assert_exactly_one_yocto();
+
+        self.assert_admin();
+
+        assert_condition(!self.paused, RafflerError::ContractAlreadyPaused);
+
+        self.paused = true;
+    }
+
+    #[witgen]
+    #[payable]
+    pub fn unpause(&mut self) {
+        assert_exactly_one_yocto();
+
+        self.assert_admin();
+
+        assert_condition(self.paused, RafflerError::ContractNotPaused);
+
+        self.paused = false;
+    }
+
+    #[witgen]
+    #[payable]
+    pub fn set_config(&mut self, config: Config) {
+        assert_exactly_one_yocto();
+
+        self.assert_admin();
+
+        assert_condition(
+            1 <= config.min_title_length && config.min_title_length <= config.max_title_length,
+            RafflerError::InvalidTitleLengthBounds,
+        );
+
+        assert_condition(
+            config.max_prizes_per_event >= 1,
+            RafflerError::InvalidMaxPrizesPerEvent,
+        );
+
+        assert_condition(
+            config.max_pagination_limit >= 1,
+            RafflerError::InvalidMaxPaginationLimit,
+        );
+
+        assert_condition(
+            config.fees.prize_fee_bps <= MAX_FEE_BPS && config.fees.ticket_fee_bps <= MAX_FEE_BPS,
+            RafflerError::FeeTooBig {
+                max_fee_bps: MAX_FEE_BPS,
+            },
+        );
+
+        self.config = config;
+    }
+
+    #[witgen]
+    #[payable]
+    pub fn withdraw_fees(&mut self, amount: U128, receiver_id: AccountId) -> Promise {
+        assert_exactly_one_yocto();
+
+        self.assert_admin();
+
+        assert_condition(
+            amount.0 <= self.accrued_fees,
+            RafflerError::NotEnoughAccruedFees {
+                available: self.accrued_fees,
+            },
+        );
+
+        self.accrued_fees -= amount.0;
+
+        Promise::new(receiver_id).transfer(amount.0).then(
+            Self::ext(env::current_account_id())
+                .with_static_gas(GAS_FOR_RESOLVE_WITHDRAW_FEES)
+                .resolve_withdraw_fees(amount),
+        )
+    }
+
+    /// Returns fees back to the treasury if the transfer has failed
+    #[private]
+    pub fn resolve_withdraw_fees(&mut self, amount: U128) -> bool {
+        let is_success = is_promise_success();
+
+        if !is_success {
+            self.accrued_fees += amount.0;
+        }
+
+        is_success
+    }
+
+    #[witgen]
+    #[payable]
+    pub fn register_creator(&mut self, account_id: AccountId, display_name: String) {
+        assert_at_least_one_yocto();
+
+        self.assert_admin();
+
+        assert_condition(
+            !display_name.is_empty() && display_name.len() <= MAX_DISPLAY_NAME_LENGTH,
+            RafflerError::InvalidDisplayName {
+                max: MAX_DISPLAY_NAME_LENGTH,
+            },
+        );
+
+        let storage_before = env::storage_usage();
+
+        self.verified_creators
+            .insert(&account_id, &CreatorProfile { display_name });
+
+        self.internal_pay_for_storage(
+            &env::predecessor_account_id(),
+            env::attached_deposit(),
+            storage_before,
+        );
+    }
+
+    #[witgen]
+    #[payable]
+    pub fn unregister_creator(&mut self, account_id: AccountId) {
+        assert_exactly_one_yocto();
+
+        self.assert_admin();
+
+        let storage_before = env::storage_usage();
+
+        self.verified_creators.remove(&account_id);
+
+        // the admin has paid for the profile
+        self.internal_release_storage(
+            &env::predecessor_account_id(),
+            storage_before - env::storage_usage(),
+        );
+    }
+
+    #[witgen]
+    #[payable]
+    pub fn add_to_blocklist(&mut self, entries: Vec<BlocklistEntry>) {
+        assert_at_least_one_yocto();
+
+        self.assert_admin();
+
+        let storage_before = env::storage_usage();
+
+        for entry in entries.iter() {
+            match entry {
+                BlocklistEntry::Account { account_id } => {
+                    self.blocked_accounts.insert(account_id, &())
+                }
+                BlocklistEntry::Subaccounts { account_id } => {
+                    self.blocked_subaccounts.insert(account_id, &())
+                }
+            };
+        }
+
+        self.internal_pay_for_storage(
+            &env::predecessor_account_id(),
+            env::attached_deposit(),
+            storage_before,
+        );
+    }
+
+    #[witgen]
+    #[payable]
+    pub fn remove_from_blocklist(&mut self, entries: Vec<BlocklistEntry>) {
+        assert_exactly_one_yocto();
+
+        self.assert_admin();
+
+        let storage_before = env::storage_usage();
+
+        for entry in entries.iter() {
+            match entry {
+                BlocklistEntry::Account { account_id } => self.blocked_accounts.remove(account_id),
+                BlocklistEntry::Subaccounts { account_id } => {
+                    self.blocked_subaccounts.remove(account_id)
+                }
+            };
+        }
+
+        // the admin has paid for entries
+        self.internal_release_storage(
+            &env::predecessor_account_id(),
+            storage_before - env::storage_usage(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::admin::types::{BlocklistEntry, Config, Fees};
+    use crate::Contract;
+
+    use near_sdk::json_types::U128;
+    use near_sdk::test_utils::VMContextBuilder;
+    use near_sdk::{testing_env, MockedBlockchain, PromiseResult, RuntimeFeesConfig, VMConfig};
+
+    const START_TIME: u64 = 2_000_000;
+    const ACTIVE_TIME: u64 = 3_000_000;
+    const END_TIME: u64 = 4_000_000;
+    const RAFFLE_TIME: u64 = 5_000_000;
+
+    #[test]
+    fn pass_set_admin() {
+        let mut context = VMContextBuilder::new();
+
+        testing_env!(context
+            .predecessor_account_id("admin".try_into().unwrap())
+            .build());
+        let mut contract = Contract::new();
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.set_admin("admin2".to_string());
+
+        assert_eq!(contract.get_admin(), "admin2".to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "This method can be accessed only by the contract admin")]
+    fn panic_on_no_admin_pausing_contract() {
+        let mut context = VMContextBuilder::new();
+
+        testing_env!(context
+            .predecessor_account_id("admin".try_into().unwrap())
+            .build());
+        let mut contract = Contract::new();
+
+        testing_env!(context
+            .attached_deposit(1)
+            .predecessor_account_id("den".try_into().unwrap())
+            .build());
+        contract.pause();
+    }
+
+    #[test]
+    #[should_panic(expected = "Contract is paused")]
+    fn panic_on_add_event_while_paused() {
+        let mut context = VMContextBuilder::new();
+
+        testing_env!(context
+            .predecessor_account_id("admin".try_into().unwrap())
+            .build());
+        let mut contract = Contract::new();
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.pause();
+
+        assert!(contract.is_paused());
+
+        testing_env!(context
+            .attached_deposit(2_000_000_000_000_000_000_000_000)
+            .predecessor_account_id("owner".try_into().unwrap())
+            .build());
+        contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+    }
+
+    #[test]
+    fn pass_add_event_after_unpause() {
+        let mut context = VMContextBuilder::new();
+
+        testing_env!(context
+            .predecessor_account_id("admin".try_into().unwrap())
+            .build());
+        let mut contract = Contract::new();
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.pause();
+
+        testing_env!(context.build());
+        contract.unpause();
+
+        assert!(!contract.is_paused());
+
+        testing_env!(context
+            .attached_deposit(2_000_000_000_000_000_000_000_000)
+            .predecessor_account_id("owner".try_into().unwrap())
+            .build());
+        contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "'title' should be between 10 and 20 symbols")]
+    fn panic_on_add_event_with_title_out_of_config_bounds() {
+        let mut context = VMContextBuilder::new();
+
+        testing_env!(context
+            .predecessor_account_id("admin".try_into().unwrap())
+            .build());
+        let mut contract = Contract::new();
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.set_config(Config {
+            min_title_length: 10,
+            max_title_length: 20,
+            ..Config::default()
+        });
+
+        testing_env!(context
+            .attached_deposit(2_000_000_000_000_000_000_000_000)
+            .predecessor_account_id("owner".try_into().unwrap())
+            .build());
+        contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Event could have max 1 prizes")]
+    fn panic_on_adding_more_near_prizes_than_config_allows() {
+        let mut context = VMContextBuilder::new();
+
+        testing_env!(context
+            .predecessor_account_id("admin".try_into().unwrap())
+            .build());
+        let mut contract = Contract::new();
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.set_config(Config {
+            max_prizes_per_event: 1,
+            ..Config::default()
+        });
+
+        testing_env!(context
+            .attached_deposit(2_000_000_000_000_000_000_000_000)
+            .predecessor_account_id("owner".try_into().unwrap())
+            .build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
+    }
+
+    #[test]
+    fn pass_charge_prize_fee_and_withdraw_fees() {
+        let mut context = VMContextBuilder::new();
+
+        testing_env!(context
+            .predecessor_account_id("admin".try_into().unwrap())
+            .build());
+        let mut contract = Contract::new();
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.set_config(Config {
+            fees: Fees {
+                prize_fee_bps: 500,
+                ticket_fee_bps: 0,
+            },
+            ..Config::default()
+        });
+
+        testing_env!(context
+            .attached_deposit(2_000_000_000_000_000_000_000_000)
+            .predecessor_account_id("owner".try_into().unwrap())
+            .build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
+
+        // 5% of 1N
+        assert_eq!(
+            contract.get_accrued_fees(),
+            U128(50_000_000_000_000_000_000_000)
+        );
+
+        testing_env!(context
+            .attached_deposit(1)
+            .predecessor_account_id("admin".try_into().unwrap())
+            .build());
+        contract.withdraw_fees(U128(20_000_000_000_000_000_000_000), "treasury".to_string());
+
+        assert_eq!(
+            contract.get_accrued_fees(),
+            U128(30_000_000_000_000_000_000_000)
+        );
+    }
+
+    #[test]
+    fn pass_restore_fees_on_failed_withdrawal() {
+        let mut context = VMContextBuilder::new();
+
+        testing_env!(context
+            .current_account_id("raffler".try_into().unwrap())
+            .predecessor_account_id("admin".try_into().unwrap())
+            .build());
+        let mut contract = Contract::new();
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.set_config(Config {
+            fees: Fees {
+                prize_fee_bps: 500,
+                ticket_fee_bps: 0,
+            },
+            ..Config::default()
+        });
+
+        testing_env!(context
+            .attached_deposit(2_000_000_000_000_000_000_000_000)
+            .predecessor_account_id("owner".try_into().unwrap())
+            .build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        contract.add_near_prize(event_id, U128(1_000_000_000_000_000_000_000_000), None);
+
+        let accrued_fees = contract.get_accrued_fees();
+
+        testing_env!(context
+            .attached_deposit(1)
+            .predecessor_account_id("admin".try_into().unwrap())
+            .build());
+        contract.withdraw_fees(accrued_fees, "treasury".to_string());
+
+        assert_eq!(contract.get_accrued_fees(), U128(0));
+
+        testing_env!(
+            context
+                .attached_deposit(0)
+                .predecessor_account_id("raffler".try_into().unwrap())
+                .build(),
+            VMConfig::test(),
+            RuntimeFeesConfig::test(),
+            Default::default(),
+            vec![PromiseResult::Failed],
+        );
+
+        assert!(!contract.resolve_withdraw_fees(accrued_fees));
+        assert_eq!(contract.get_accrued_fees(), accrued_fees);
+    }
+
+    #[test]
+    #[should_panic(expected = "You should attach at least")]
+    fn panic_on_adding_near_prize_without_fee() {
+        let mut context = VMContextBuilder::new();
+
+        testing_env!(context
+            .predecessor_account_id("admin".try_into().unwrap())
+            .build());
+        let mut contract = Contract::new();
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.set_config(Config {
+            fees: Fees {
+                prize_fee_bps: 500,
+                ticket_fee_bps: 0,
+            },
+            ..Config::default()
+        });
+
+        testing_env!(context
+            .attached_deposit(2_000_000_000_000_000_000_000_000)
+            .predecessor_account_id("owner".try_into().unwrap())
+            .build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        testing_env!(context
+            .attached_deposit(1_000_000_000_000_000_000_000_000)
+            .build());
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Couldn't withdraw more than 0 yoctoNear of accrued fees")]
+    fn panic_on_withdrawing_more_than_accrued_fees() {
+        let mut context = VMContextBuilder::new();
+
+        testing_env!(context
+            .predecessor_account_id("admin".try_into().unwrap())
+            .build());
+        let mut contract = Contract::new();
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.withdraw_fees(U128(1), "treasury".to_string());
+    }
+
+    #[test]
+    fn pass_charge_ticket_fee_by_event_snapshot() {
+        let mut context = VMContextBuilder::new();
+
+        testing_env!(context
+            .predecessor_account_id("admin".try_into().unwrap())
+            .build());
+        let mut contract = Contract::new();
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.set_config(Config {
+            fees: Fees {
+                prize_fee_bps: 0,
+                ticket_fee_bps: 1_000,
+            },
+            ..Config::default()
+        });
+
+        testing_env!(context
+            .attached_deposit(2_000_000_000_000_000_000_000_000)
+            .predecessor_account_id("owner".try_into().unwrap())
+            .build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.set_event_ticket_price(event_id.clone(), U128(1_000_000_000_000_000_000_000_000));
+
+        testing_env!(context.build());
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.set_event_visible(event_id.clone());
+
+        // change of fees shouldn't affect already created event
+        testing_env!(context
+            .attached_deposit(1)
+            .predecessor_account_id("admin".try_into().unwrap())
+            .build());
+        contract.set_config(Config::default());
+
+        testing_env!(context
+            .attached_deposit(2_000_000_000_000_000_000_000_000)
+            .block_timestamp(ACTIVE_TIME * 1_000_000)
+            .predecessor_account_id("den".try_into().unwrap())
+            .build());
+        contract.join_event(event_id.clone());
+
+        testing_env!(context
+            .predecessor_account_id("owner".try_into().unwrap())
+            .block_timestamp(RAFFLE_TIME * 1_000_000)
+            .build());
+        contract.raffle_event_prizes(event_id.clone());
+
+        // 10% of 1N ticket
+        assert_eq!(
+            contract.get_accrued_fees(),
+            U128(100_000_000_000_000_000_000_000)
+        );
+    }
+
+    #[test]
+    fn pass_block_accounts_and_subaccounts() {
+        let mut context = VMContextBuilder::new();
+
+        testing_env!(context
+            .predecessor_account_id("admin".try_into().unwrap())
+            .build());
+        let mut contract = Contract::new();
+
+        testing_env!(context
+            .attached_deposit(100_000_000_000_000_000_000_000)
+            .build());
+        contract.add_to_blocklist(vec![
+            BlocklistEntry::Account {
+                account_id: "bot.near".to_string(),
+            },
+            BlocklistEntry::Subaccounts {
+                account_id: "farm.near".to_string(),
+            },
+        ]);
+
+        // entries are paid from the storage balance of the admin
+        let available_before = contract
+            .storage_balance_of("admin".try_into().unwrap())
+            .unwrap()
+            .available;
+
+        assert!(contract.is_blocked("bot.near".to_string()));
+        assert!(contract.is_blocked("a.farm.near".to_string()));
+        assert!(contract.is_blocked("b.a.farm.near".to_string()));
+        assert!(!contract.is_blocked("farm.near".to_string()));
+        assert!(!contract.is_blocked("subbot.near".to_string()));
+        assert_eq!(contract.get_blocked_subaccounts(None).total, 1);
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.remove_from_blocklist(vec![BlocklistEntry::Subaccounts {
+            account_id: "farm.near".to_string(),
+        }]);
+
+        assert!(
+            contract
+                .storage_balance_of("admin".try_into().unwrap())
+                .unwrap()
+                .available
+                .0
+                > available_before.0
+        );
+
+        assert!(!contract.is_blocked("a.farm.near".to_string()));
+        assert_eq!(
+            contract.get_blocked_accounts(None).items,
+            vec!["bot.near".to_string()]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "This method can be accessed only by the contract admin")]
+    fn panic_on_no_admin_adding_to_blocklist() {
+        let mut context = VMContextBuilder::new();
+
+        testing_env!(context
+            .predecessor_account_id("admin".try_into().unwrap())
+            .build());
+        let mut contract = Contract::new();
+
+        testing_env!(context
+            .predecessor_account_id("den".try_into().unwrap())
+            .attached_deposit(1)
+            .build());
+        contract.add_to_blocklist(vec![BlocklistEntry::Account {
+            account_id: "bot.near".to_string(),
+        }]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Account is blocked (account bot.near)")]
+    fn panic_on_adding_event_by_blocked_account() {
+        let mut context = VMContextBuilder::new();
+
+        testing_env!(context
+            .predecessor_account_id("admin".try_into().unwrap())
+            .build());
+        let mut contract = Contract::new();
+
+        testing_env!(context
+            .attached_deposit(100_000_000_000_000_000_000_000)
+            .build());
+        contract.add_to_blocklist(vec![BlocklistEntry::Account {
+            account_id: "bot.near".to_string(),
+        }]);
+
+        testing_env!(context
+            .predecessor_account_id("bot.near".try_into().unwrap())
+            .attached_deposit(2_000_000_000_000_000_000_000_000)
+            .build());
+        contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+    }
+
+    #[test]
+    fn pass_register_creator_and_mark_events_verified() {
+        let mut context = VMContextBuilder::new();
+
+        testing_env!(context
+            .predecessor_account_id("admin".try_into().unwrap())
+            .build());
+        let mut contract = Contract::new();
+
+        testing_env!(context
+            .attached_deposit(100_000_000_000_000_000_000_000)
+            .build());
+        contract.register_creator("brand.near".to_string(), String::from("Brand"));
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.set_config(Config {
+            only_verified_creators: true,
+            ..Config::default()
+        });
+
+        let available_before = contract
+            .storage_balance_of("admin".try_into().unwrap())
+            .unwrap()
+            .available;
+
+        testing_env!(context
+            .predecessor_account_id("brand.near".try_into().unwrap())
+            .attached_deposit(2_000_000_000_000_000_000_000_000)
+            .build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        assert!(contract.get_event(event_id).unwrap().verified);
+        assert_eq!(
+            contract
+                .get_creator("brand.near".to_string())
+                .unwrap()
+                .display_name,
+            "Brand"
+        );
+        assert_eq!(contract.get_creators(None).total, 1);
+
+        testing_env!(context
+            .predecessor_account_id("admin".try_into().unwrap())
+            .attached_deposit(1)
+            .build());
+        contract.unregister_creator("brand.near".to_string());
+
+        // the profile was paid by the admin
+        assert!(
+            contract
+                .storage_balance_of("admin".try_into().unwrap())
+                .unwrap()
+                .available
+                .0
+                > available_before.0
+        );
+
+        assert!(!contract.get_event(event_id).unwrap().verified);
+        assert!(contract.get_creator("brand.near".to_string()).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Only verified creators are able to create events")]
+    fn panic_on_adding_event_by_not_verified_creator() {
+        let mut context = VMContextBuilder::new();
+
+        testing_env!(context
+            .predecessor_account_id("admin".try_into().unwrap())
+            .build());
+        let mut contract = Contract::new();
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.set_config(Config {
+            only_verified_creators: true,
+            ..Config::default()
+        });
+
+        testing_env!(context
+            .predecessor_account_id("owner".try_into().unwrap())
+            .attached_deposit(2_000_000_000_000_000_000_000_000)
+            .build());
+        contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use crate::event::types::MIN_NEAR_PRIZE_AMOUNT;
+use crate::types::{BASIS_POINTS, MAX_PAGINATION_LIMIT};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::AccountId;
+use witgen::witgen;
+
+pub const DEFAULT_MAX_PRIZES_PER_EVENT: u64 = 5;
+pub const DEFAULT_MIN_TITLE_LENGTH: u64 = 4;
+pub const DEFAULT_MAX_TITLE_LENGTH: u64 = 64;
+// active events could be extended by 7 days in total
+pub const DEFAULT_MAX_EVENT_EXTENSION: u64 = 7 * 24 * 3_600 * 1_000;
+pub const MAX_DISPLAY_NAME_LENGTH: usize = 64;
+// fee could take the whole amount at most
+pub const MAX_FEE_BPS: u32 = BASIS_POINTS;
+
+#[witgen]
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Config {
+    pub min_near_prize_amount: U128,
+    pub max_prizes_per_event: u64,
+    pub min_title_length: u64,
+    pub max_title_length: u64,
+    pub max_pagination_limit: u64,
+    pub max_event_extension: u64,     // in milliseconds
+    pub only_verified_creators: bool, // only registered creators are able to create events
+    pub fees: Fees,
+}
+
+// platform fees in basis points, every event takes a snapshot of them on creation
+#[witgen]
+#[derive(
+    BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Default,
+)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Fees {
+    pub prize_fee_bps: u32,  // charged on top of prize deposits
+    pub ticket_fee_bps: u32, // withheld from ticket sales
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            min_near_prize_amount: U128(MIN_NEAR_PRIZE_AMOUNT),
+            max_prizes_per_event: DEFAULT_MAX_PRIZES_PER_EVENT,
+            min_title_length: DEFAULT_MIN_TITLE_LENGTH,
+            max_title_length: DEFAULT_MAX_TITLE_LENGTH,
+            max_pagination_limit: MAX_PAGINATION_LIMIT,
+            max_event_extension: DEFAULT_MAX_EVENT_EXTENSION,
+            only_verified_creators: false,
+            fees: Fees::default(),
+        }
+    }
+}
+
+// blocked accounts couldn't create & join events or claim prizes
+#[witgen]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(crate = "near_sdk::serde", tag = "type")]
+pub enum BlocklistEntry {
+    Account { account_id: AccountId },
+    Subaccounts { account_id: AccountId }, // all accounts ending with ".{account_id}"
+}
+
+// verified creator, events of which are marked as verified
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct CreatorProfile {
+    pub display_name: String,
+}
+
+#[witgen]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonCreator {
+    pub account_id: AccountId,
+    pub display_name: String,
+}
@@ -0,0 +1,96 @@
+use crate::types::{CursorPagination, Page};
+use crate::utils::{get_key_page, get_page};
+use crate::*;
+use near_sdk::json_types::U128;
+use near_sdk::AccountId;
+
+use super::types::{Config, JsonCreator};
+use witgen::witgen;
+
+#[near_bindgen]
+impl Contract {
+    #[witgen]
+    pub fn get_admin(&self) -> AccountId {
+        self.admin_id.clone()
+    }
+
+    #[witgen]
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    #[witgen]
+    pub fn get_config(&self) -> Config {
+        self.config.clone()
+    }
+
+    #[witgen]
+    pub fn get_accrued_fees(&self) -> U128 {
+        U128(self.accrued_fees)
+    }
+
+    #[witgen]
+    pub fn get_creator(&self, account_id: AccountId) -> Option<JsonCreator> {
+        self.verified_creators
+            .get(&account_id)
+            .map(|profile| JsonCreator {
+                account_id,
+                display_name: profile.display_name,
+            })
+    }
+
+    #[witgen]
+    pub fn get_creators(
+        &self,
+        pagination: Option<CursorPagination<AccountId>>,
+    ) -> Page<JsonCreator, AccountId> {
+        let page = get_page(
+            &self.verified_creators,
+            pagination,
+            self.config.max_pagination_limit,
+        );
+
+        Page {
+            items: page
+                .items
+                .into_iter()
+                .map(|(account_id, profile)| JsonCreator {
+                    account_id,
+                    display_name: profile.display_name,
+                })
+                .collect(),
+            next_key: page.next_key,
+            total: page.total,
+        }
+    }
+
+    #[witgen]
+    pub fn is_blocked(&self, account_id: AccountId) -> bool {
+        self.internal_is_blocked(&account_id)
+    }
+
+    #[witgen]
+    pub fn get_blocked_accounts(
+        &self,
+        pagination: Option<CursorPagination<AccountId>>,
+    ) -> Page<AccountId, AccountId> {
+        get_key_page(
+            &self.blocked_accounts,
+            pagination,
+            self.config.max_pagination_limit,
+        )
+    }
+
+    // parent accounts, all subaccounts of which are blocked
+    #[witgen]
+    pub fn get_blocked_subaccounts(
+        &self,
+        pagination: Option<CursorPagination<AccountId>>,
+    ) -> Page<AccountId, AccountId> {
+        get_key_page(
+            &self.blocked_subaccounts,
+            pagination,
+            self.config.max_pagination_limit,
+        )
+    }
+}
@@ -0,0 +1,476 @@
+use crate::event::types::{EventId, EventStatus};
+use crate::series::types::SeriesId;
+use near_sdk::{env, AccountId, Balance};
+
+// every error has a stable code, which is rendered in front of the message,
+// e.g. "E206: Event status isn't 'Visible' (event 1 is in 'Configuration')",
+// so clients could rely on the code instead of matching the text
+#[derive(Debug, PartialEq)]
+pub enum RafflerError {
+    // contract
+    NotContractAdmin,
+    ContractPaused,
+    ContractAlreadyPaused,
+    ContractNotPaused,
+    InvalidTitleLengthBounds,
+    InvalidMaxPrizesPerEvent,
+    InvalidMaxPaginationLimit,
+    FeeTooBig {
+        max_fee_bps: u32,
+    },
+    NotEnoughAccruedFees {
+        available: Balance,
+    },
+    DepositLessThanOneYocto,
+    DepositNotOneYocto,
+    NotEnoughDeposit {
+        required: Balance,
+    },
+    AccountBlocked {
+        account_id: AccountId,
+    },
+    InvalidDisplayName {
+        max: usize,
+    },
+    NotVerifiedCreator,
+    InvalidPaginationLimit,
+    // events
+    EventNotFound {
+        event_id: EventId,
+    },
+    EventAlreadyExists {
+        event_id: EventId,
+    },
+    InvalidTitleLength {
+        min: u64,
+        max: u64,
+    },
+    IncompleteEventTime,
+    EndTimeBeforeStartTime,
+    StartTimeInPast,
+    InvalidEventStatus {
+        event_id: EventId,
+        expected: EventStatus,
+        actual: EventStatus,
+    },
+    EventWithoutPrizes {
+        event_id: EventId,
+    },
+    EventAlreadyEnded {
+        event_id: EventId,
+    },
+    TooManyPrizes {
+        max: u64,
+    },
+    PrizeAmountTooSmall {
+        min: Balance,
+    },
+    OwnerCantParticipate,
+    AlreadyParticipating {
+        event_id: EventId,
+    },
+    EventWithoutParticipants {
+        event_id: EventId,
+    },
+    PrizeNotFound,
+    PrizesNotSettled {
+        event_id: EventId,
+    },
+    NotEventOwner,
+    NoEventPermission,
+    AlreadyEventOwner {
+        account_id: AccountId,
+    },
+    OwnershipNotProposed,
+    OwnerHasAllPermissions,
+    NoEventRole {
+        account_id: AccountId,
+    },
+    AlreadyBanned {
+        account_id: AccountId,
+    },
+    InvalidMinParticipants {
+        max: u64,
+    },
+    InvalidPrizeQuantity {
+        max: u64,
+    },
+    InvalidPrizeSplit {
+        max: u64,
+    },
+    MetadataFieldTooLong {
+        field: &'static str,
+        max: usize,
+    },
+    InvalidMetadataUrl {
+        field: &'static str,
+    },
+    InvalidMediaHash,
+    TooManyTags {
+        max: usize,
+    },
+    InvalidTagLength {
+        max: usize,
+    },
+    EventExtensionTooLong {
+        max: u64,
+    },
+    EndTimeNotExtended,
+    EventAlreadyRaffled {
+        event_id: EventId,
+    },
+    BannedFromEvent {
+        event_id: EventId,
+    },
+    SeriesNotFound {
+        series_id: SeriesId,
+    },
+    InvalidSeriesSchedule,
+    SeriesWithoutPrizes,
+    SeriesCompleted {
+        series_id: SeriesId,
+    },
+    SeriesEventNotDue {
+        series_id: SeriesId,
+    },
+    RoleHolderCantParticipate,
+    TooManyWinners {
+        max: u64,
+    },
+    // storage
+    NotEnoughRegistrationDeposit {
+        required: Balance,
+    },
+    AccountNotRegistered,
+    NotEnoughStorageBalance {
+        required: Balance,
+    },
+    NotEnoughAvailableStorage {
+        available: Balance,
+    },
+    ForceUnregisterUnsupported,
+    AccountOccupiesStorage,
+    // upgrade
+    MissingContractCode,
+    ContractNotInitialized,
+    InvalidContractState,
+}
+
+impl RafflerError {
+    pub fn code(&self) -> u32 {
+        match self {
+            RafflerError::NotContractAdmin => 100,
+            RafflerError::ContractPaused => 101,
+            RafflerError::ContractAlreadyPaused => 102,
+            RafflerError::ContractNotPaused => 103,
+            RafflerError::InvalidTitleLengthBounds => 104,
+            RafflerError::InvalidMaxPrizesPerEvent => 105,
+            RafflerError::InvalidMaxPaginationLimit => 106,
+            RafflerError::FeeTooBig { .. } => 107,
+            RafflerError::NotEnoughAccruedFees { .. } => 108,
+            RafflerError::DepositLessThanOneYocto => 110,
+            RafflerError::DepositNotOneYocto => 111,
+            RafflerError::NotEnoughDeposit { .. } => 112,
+            RafflerError::AccountBlocked { .. } => 113,
+            RafflerError::InvalidDisplayName { .. } => 114,
+            RafflerError::NotVerifiedCreator => 115,
+            RafflerError::InvalidPaginationLimit => 116,
+            RafflerError::EventNotFound { .. } => 200,
+            RafflerError::EventAlreadyExists { .. } => 201,
+            RafflerError::InvalidTitleLength { .. } => 202,
+            RafflerError::IncompleteEventTime => 203,
+            RafflerError::EndTimeBeforeStartTime => 204,
+            RafflerError::StartTimeInPast => 205,
+            RafflerError::InvalidEventStatus { .. } => 206,
+            RafflerError::EventWithoutPrizes { .. } => 207,
+            RafflerError::EventAlreadyEnded { .. } => 208,
+            RafflerError::TooManyPrizes { .. } => 209,
+            RafflerError::PrizeAmountTooSmall { .. } => 210,
+            RafflerError::OwnerCantParticipate => 211,
+            RafflerError::AlreadyParticipating { .. } => 212,
+            RafflerError::EventWithoutParticipants { .. } => 213,
+            RafflerError::PrizeNotFound => 214,
+            RafflerError::PrizesNotSettled { .. } => 215,
+            RafflerError::NotEventOwner => 220,
+            RafflerError::NoEventPermission => 221,
+            RafflerError::AlreadyEventOwner { .. } => 222,
+            RafflerError::OwnershipNotProposed => 223,
+            RafflerError::OwnerHasAllPermissions => 224,
+            RafflerError::NoEventRole { .. } => 225,
+            RafflerError::AlreadyBanned { .. } => 226,
+            RafflerError::InvalidMinParticipants { .. } => 227,
+            RafflerError::InvalidPrizeQuantity { .. } => 228,
+            RafflerError::InvalidPrizeSplit { .. } => 229,
+            RafflerError::MetadataFieldTooLong { .. } => 230,
+            RafflerError::InvalidMetadataUrl { .. } => 231,
+            RafflerError::InvalidMediaHash => 232,
+            RafflerError::TooManyTags { .. } => 233,
+            RafflerError::InvalidTagLength { .. } => 234,
+            RafflerError::EventExtensionTooLong { .. } => 216,
+            RafflerError::EndTimeNotExtended => 217,
+            RafflerError::EventAlreadyRaffled { .. } => 218,
+            RafflerError::BannedFromEvent { .. } => 219,
+            RafflerError::SeriesNotFound { .. } => 240,
+            RafflerError::InvalidSeriesSchedule => 241,
+            RafflerError::SeriesWithoutPrizes => 242,
+            RafflerError::SeriesCompleted { .. } => 243,
+            RafflerError::SeriesEventNotDue { .. } => 244,
+            RafflerError::RoleHolderCantParticipate => 250,
+            RafflerError::TooManyWinners { .. } => 251,
+            RafflerError::NotEnoughRegistrationDeposit { .. } => 300,
+            RafflerError::AccountNotRegistered => 301,
+            RafflerError::NotEnoughStorageBalance { .. } => 302,
+            RafflerError::NotEnoughAvailableStorage { .. } => 303,
+            RafflerError::ForceUnregisterUnsupported => 304,
+            RafflerError::AccountOccupiesStorage => 305,
+            RafflerError::MissingContractCode => 400,
+            RafflerError::ContractNotInitialized => 401,
+            RafflerError::InvalidContractState => 402,
+        }
+    }
+
+    pub fn message(&self) -> String {
+        match self {
+            RafflerError::NotContractAdmin => {
+                "This method can be accessed only by the contract admin".to_string()
+            }
+            RafflerError::ContractPaused => "Contract is paused".to_string(),
+            RafflerError::ContractAlreadyPaused => "Contract is already paused".to_string(),
+            RafflerError::ContractNotPaused => "Contract isn't paused".to_string(),
+            RafflerError::InvalidTitleLengthBounds => {
+                "'min_title_length' should be between 1 and 'max_title_length'".to_string()
+            }
+            RafflerError::InvalidMaxPrizesPerEvent => {
+                "'max_prizes_per_event' should be at least 1".to_string()
+            }
+            RafflerError::InvalidMaxPaginationLimit => {
+                "'max_pagination_limit' should be at least 1".to_string()
+            }
+            RafflerError::FeeTooBig { max_fee_bps } => {
+                format!("Fees couldn't be bigger than {} basis points", max_fee_bps)
+            }
+            RafflerError::NotEnoughAccruedFees { available } => format!(
+                "Couldn't withdraw more than {} yoctoNear of accrued fees",
+                available
+            ),
+            RafflerError::DepositLessThanOneYocto => {
+                "Requires attached deposit of at least 1 yoctoNEAR".to_string()
+            }
+            RafflerError::DepositNotOneYocto => {
+                "Requires attached deposit of exactly 1 yoctoNEAR".to_string()
+            }
+            RafflerError::NotEnoughDeposit { required } => {
+                format!("You should attach at least {} yoctoNear", required)
+            }
+            RafflerError::AccountBlocked { account_id } => {
+                format!("Account is blocked (account {})", account_id)
+            }
+            RafflerError::InvalidDisplayName { max } => {
+                format!("'display_name' should be between 1 and {} bytes", max)
+            }
+            RafflerError::NotVerifiedCreator => {
+                "Only verified creators are able to create events".to_string()
+            }
+            RafflerError::InvalidPaginationLimit => {
+                "Pagination limit should be at least 1".to_string()
+            }
+            RafflerError::EventNotFound { event_id } => {
+                format!("Couldn't find event with id - '{}'", event_id)
+            }
+            RafflerError::EventAlreadyExists { event_id } => format!(
+                "Couldn't add_event because event_id is already exist (event {})",
+                event_id
+            ),
+            RafflerError::InvalidTitleLength { min, max } => {
+                format!("'title' should be between {} and {} symbols", min, max)
+            }
+            RafflerError::IncompleteEventTime => {
+                "Both 'start_time' and 'end_time' either must be present or absent".to_string()
+            }
+            RafflerError::EndTimeBeforeStartTime => {
+                "'end_time' must be bigger than 'start_time'".to_string()
+            }
+            RafflerError::StartTimeInPast => {
+                "'start_time' couldn't be set to value in the past".to_string()
+            }
+            RafflerError::InvalidEventStatus {
+                event_id,
+                expected,
+                actual,
+            } => format!(
+                "Event status isn't '{}' (event {} is in '{}')",
+                expected, event_id, actual
+            ),
+            RafflerError::EventWithoutPrizes { event_id } => format!(
+                "Couldn't set event visible since there're no prizes (event {})",
+                event_id
+            ),
+            RafflerError::EventAlreadyEnded { event_id } => format!(
+                "Couldn't fund storage of the event which has already ended (event {})",
+                event_id
+            ),
+            RafflerError::TooManyPrizes { max } => format!("Event could have max {} prizes", max),
+            RafflerError::PrizeAmountTooSmall { min } => {
+                format!("Prize amount couldn't be less than {} yoctoNear", min)
+            }
+            RafflerError::OwnerCantParticipate => {
+                "Owner can't participate his own events".to_string()
+            }
+            RafflerError::AlreadyParticipating { event_id } => format!(
+                "You're already participating in this event (event {})",
+                event_id
+            ),
+            RafflerError::EventWithoutParticipants { event_id } => {
+                format!("Event has no participants (event {})", event_id)
+            }
+            RafflerError::PrizeNotFound => "Couldn't claim prize since it doesn't exist".to_string(),
+            RafflerError::PrizesNotSettled { event_id } => format!(
+                "Couldn't archive event until all prizes are claimed or expired (event {})",
+                event_id
+            ),
+            RafflerError::NotEventOwner => "This method can be accessed only by the owner".to_string(),
+            RafflerError::NoEventPermission => {
+                "This method can be accessed only by the owner or accounts with a suitable role"
+                    .to_string()
+            }
+            RafflerError::AlreadyEventOwner { account_id } => format!(
+                "Account is already the owner of this event (account {})",
+                account_id
+            ),
+            RafflerError::OwnershipNotProposed => {
+                "Ownership of this event wasn't proposed to you".to_string()
+            }
+            RafflerError::OwnerHasAllPermissions => {
+                "Owner already has all permissions of the event".to_string()
+            }
+            RafflerError::NoEventRole { account_id } => format!(
+                "Account doesn't have any role in this event (account {})",
+                account_id
+            ),
+            RafflerError::MetadataFieldTooLong { field, max } => {
+                format!("'{}' couldn't be longer than {} bytes", field, max)
+            }
+            RafflerError::InvalidMetadataUrl { field } => {
+                format!("'{}' must be an 'https://' or 'ipfs://' URL", field)
+            }
+            RafflerError::InvalidMediaHash => {
+                "'media_hash' must be a sha256 of 'media' and is required along with it"
+                    .to_string()
+            }
+            RafflerError::TooManyTags { max } => format!("Event could have max {} tags", max),
+            RafflerError::InvalidTagLength { max } => {
+                format!("Every tag should be between 1 and {} bytes", max)
+            }
+            RafflerError::EventExtensionTooLong { max } => format!(
+                "Event couldn't be extended by more than {} milliseconds in total",
+                max
+            ),
+            RafflerError::EndTimeNotExtended => {
+                "'end_time' must be bigger than the current end of event".to_string()
+            }
+            RafflerError::EventAlreadyRaffled { event_id } => {
+                format!("Prizes of the event are already raffled (event {})", event_id)
+            }
+            RafflerError::BannedFromEvent { event_id } => {
+                format!("You're banned from this event (event {})", event_id)
+            }
+            RafflerError::AlreadyBanned { account_id } => format!(
+                "Account is already banned from this event (account {})",
+                account_id
+            ),
+            RafflerError::InvalidMinParticipants { max } => {
+                format!("'min_participants' should be at most {}", max)
+            }
+            RafflerError::InvalidPrizeQuantity { max } => {
+                format!("Prize quantity should be between 1 and {}", max)
+            }
+            RafflerError::InvalidPrizeSplit { max } => format!(
+                "Prize should be split among 1 to {} winners, percentages should be positive and sum up to 100%",
+                max
+            ),
+            RafflerError::SeriesNotFound { series_id } => {
+                format!("Couldn't find series with id - '{}'", series_id)
+            }
+            RafflerError::InvalidSeriesSchedule => {
+                "Series should have at least 1 event, non-zero 'duration' and 'interval' not less than 'duration'"
+                    .to_string()
+            }
+            RafflerError::SeriesWithoutPrizes => "Series should have at least 1 prize".to_string(),
+            RafflerError::SeriesCompleted { series_id } => format!(
+                "All events of the series are already created (series {})",
+                series_id
+            ),
+            RafflerError::SeriesEventNotDue { series_id } => format!(
+                "Previous event of the series hasn't ended yet (series {})",
+                series_id
+            ),
+            RafflerError::RoleHolderCantParticipate => {
+                "Accounts with a role in the event can't participate in it".to_string()
+            }
+            RafflerError::TooManyWinners { max } => {
+                format!("Event could have max {} winners across all prizes", max)
+            }
+            RafflerError::NotEnoughRegistrationDeposit { required } => format!(
+                "Must attach at least {} yoctoNear to register the account",
+                required
+            ),
+            RafflerError::AccountNotRegistered => "The account isn't registered".to_string(),
+            RafflerError::NotEnoughStorageBalance { required } => format!(
+                "Not enough storage balance, attach or deposit with 'storage_deposit' at least {} yoctoNear more",
+                required
+            ),
+            RafflerError::NotEnoughAvailableStorage { available } => format!(
+                "Couldn't withdraw more than {} yoctoNear of available storage balance",
+                available
+            ),
+            RafflerError::ForceUnregisterUnsupported => {
+                "Force unregistration isn't supported".to_string()
+            }
+            RafflerError::AccountOccupiesStorage => {
+                "Couldn't unregister the account which still occupies storage".to_string()
+            }
+            RafflerError::MissingContractCode => {
+                "Contract code must be attached as input".to_string()
+            }
+            RafflerError::ContractNotInitialized => "Contract isn't initialized".to_string(),
+            RafflerError::InvalidContractState => {
+                "Couldn't read the previous contract state".to_string()
+            }
+        }
+    }
+
+    pub fn panic(&self) -> ! {
+        env::panic_str(&self.to_string())
+    }
+}
+
+impl std::fmt::Display for RafflerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "E{}: {}", self.code(), self.message())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RafflerError;
+    use crate::event::types::EventStatus;
+
+    #[test]
+    fn pass_render_error_with_code() {
+        let error = RafflerError::InvalidEventStatus {
+            event_id: 1,
+            expected: EventStatus::Visible,
+            actual: EventStatus::Configuration,
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "E206: Event status isn't 'Visible' (event 1 is in 'Configuration')"
+        );
+
+        assert_eq!(
+            RafflerError::NotEnoughDeposit { required: 5 }.to_string(),
+            "E112: You should attach at least 5 yoctoNear"
+        );
+    }
+}
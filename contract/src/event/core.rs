@@ -1,63 +1,634 @@
+use crate::errors::RafflerError;
+use crate::logs::types::{
+    AddEventLog, AddNearPrizeLog, AddSplitPotPrizeLog, FailEventLog, PrizeWinnerLog,
+    RaffleEventPrizesLog, RafflerLogEvent, SetEventVisibleLog,
+};
+use crate::types::TimestampMs;
+use crate::utils::{
+    assert_condition, current_time_ms, get_fee_amount, get_storage_cost, to_storage_key,
+};
 use crate::{Contract, ContractExt};
-use near_sdk::collections::UnorderedSet;
-use near_sdk::AccountId;
-use near_sdk::{env, near_bindgen};
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::collections::{LookupMap, TreeMap, UnorderedSet, Vector};
+use near_sdk::json_types::U128;
+use near_sdk::{env, near_bindgen, Promise};
+use near_sdk::{AccountId, Balance, StorageUsage};
 
 use super::types::Event;
 use super::types::EventId;
+use super::types::EventMetadata;
 use super::types::EventPrize;
+use super::types::EventPrizeV1;
+use super::types::EventRole;
+use super::types::EventSummary;
+use super::types::EventV1;
+use super::types::JsonEvent;
 use super::types::Prize;
-use super::utils::get_random_event_participant_id;
+use super::types::PrizeSplit;
+use super::types::PrizeType;
+use super::types::PrizeWinner;
+use super::types::VersionedEvent;
+use super::types::VersionedPrize;
+use super::types::MAX_PRIZE_QUANTITY;
+use super::types::MAX_WINNERS_PER_EVENT;
+use super::utils::{
+    assert_event_metadata, assert_prize_split, get_event_json, get_event_storage_prefix,
+    get_event_summary_json, get_prize_slot_amounts, get_prize_total_amount,
+    get_random_event_participant_ids,
+};
+
+// events created before versioning was introduced
+const LEGACY_EVENTS_PREFIX: &str = "e";
+
+fn legacy_events() -> LookupMap<EventId, EventV1> {
+    LookupMap::new(to_storage_key(LEGACY_EVENTS_PREFIX))
+}
+
+// mappings by account of the same release, their entries are moved along with events
+// except of participants, which are too many to be moved in a single call
+fn legacy_events_by_owner() -> LookupMap<AccountId, UnorderedSet<EventId>> {
+    LookupMap::new(to_storage_key("eo"))
+}
+
+fn legacy_events_by_participant() -> LookupMap<AccountId, UnorderedSet<EventId>> {
+    LookupMap::new(to_storage_key("ep"))
+}
+
+fn legacy_unclaimed_prizes_by_account() -> LookupMap<AccountId, UnorderedSet<EventPrizeV1>> {
+    LookupMap::new(to_storage_key("upa"))
+}
+
+fn remove_legacy_entry<T: BorshSerialize + BorshDeserialize>(
+    mut mapping: LookupMap<AccountId, UnorderedSet<T>>,
+    account_id: &AccountId,
+    entry: &T,
+) {
+    let mut entries = match mapping.get(account_id) {
+        Some(entries) => entries,
+        None => return,
+    };
+
+    entries.remove(entry);
+
+    if entries.is_empty() {
+        mapping.remove(account_id);
+    } else {
+        mapping.insert(account_id, &entries);
+    }
+}
 
 #[near_bindgen]
 impl Contract {
-    pub(super) fn internal_get_event(&self, id: &EventId) -> Event {
-        let msg = format!("Couldn't find event with id - '{}'", &id);
+    pub(crate) fn internal_find_event(&self, id: &EventId) -> Option<Event> {
+        match self.events.get(id) {
+            Some(e) => Some(Event::from(e)),
+            // event might be created before versioning was introduced
+            None => legacy_events().get(id).map(Event::from),
+        }
+    }
+
+    // archived events are represented by their summary, verification is derived
+    // from the current registry of creators
+    pub(crate) fn internal_find_event_json(&self, id: &EventId) -> Option<JsonEvent> {
+        match self.internal_find_event(id) {
+            Some(e) => {
+                let verified = self.internal_is_verified_creator(&e.owner_id);
+
+                Some(get_event_json(&e, verified))
+            }
+            None => self.archived_events.get(id).map(|summary| {
+                let verified = self.internal_is_verified_creator(&summary.owner_id);
+
+                get_event_summary_json(&summary, verified)
+            }),
+        }
+    }
+
+    pub(crate) fn internal_get_event(&mut self, id: &EventId) -> Event {
+        // move legacy event under versioned storage, so it will be saved in the current layout
+        if let Some(legacy_event) = legacy_events().remove(id) {
+            let event = Event::from(legacy_event);
+
+            // legacy events weren't indexed, so visible ones are listed once migrated
+            if event.is_visible {
+                self.internal_add_visible_event(&event);
+            }
+
+            self.internal_migrate_legacy_entries(&event);
+
+            self.internal_save_event(event);
+        }
 
         let event = self.events.get(&id);
 
         match event {
-            Option::None => env::panic_str(msg.as_str()),
-            Option::Some(e) => e,
+            Option::None => RafflerError::EventNotFound { event_id: *id }.panic(),
+            Option::Some(e) => Event::from(e),
+        }
+    }
+
+    // moves entries of legacy event from the mappings by owner & by winner to the ordered ones,
+    // entries of participants stay in the legacy mapping & are read along with the current one
+    fn internal_migrate_legacy_entries(&mut self, event: &Event) {
+        remove_legacy_entry(legacy_events_by_owner(), &event.owner_id, &event.id);
+
+        self.internal_add_owner_event(&event.owner_id, &event.id);
+
+        for (prize_index, prize) in event.prizes.iter().enumerate() {
+            for (slot_index, winner) in Prize::from(prize).winners.iter().enumerate() {
+                if winner.claimed {
+                    continue;
+                }
+
+                remove_legacy_entry(
+                    legacy_unclaimed_prizes_by_account(),
+                    &winner.account_id,
+                    &EventPrizeV1 {
+                        event_id: event.id,
+                        prize_index: prize_index as u64,
+                    },
+                );
+
+                let event_prize = EventPrize {
+                    event_id: event.id,
+                    prize_index: prize_index as u64,
+                    slot_index: slot_index as u64,
+                };
+
+                self.internal_add_unclaimed_prize(&winner.account_id, &event_prize);
+            }
         }
     }
 
-    pub(super) fn internal_add_event(&mut self, event: Event) {
+    pub(crate) fn internal_is_legacy_event(&self, id: &EventId) -> bool {
+        legacy_events().contains_key(id)
+    }
+
+    // events of the first release which the account participates in, ordered by id
+    pub(crate) fn internal_get_legacy_participant_events(
+        &self,
+        participant_id: &AccountId,
+    ) -> Vec<EventId> {
+        let mut event_ids = legacy_events_by_participant()
+            .get(participant_id)
+            .map_or(Vec::new(), |events| events.to_vec());
+
+        event_ids.sort_unstable();
+
+        event_ids
+    }
+
+    pub(crate) fn internal_is_legacy_participant(
+        &self,
+        participant_id: &AccountId,
+        event_id: &EventId,
+    ) -> bool {
+        legacy_events_by_participant()
+            .get(participant_id)
+            .is_some_and(|events| events.contains(event_id))
+    }
+
+    // storage of the event is charged to its owner even if it's changed by a role holder,
+    // so it's released to the account which paid for it, role holders get their deposit back
+    // & could top up the storage balance of the owner with 'storage_deposit'
+    pub(crate) fn internal_pay_for_event_storage(
+        &mut self,
+        event_id: &EventId,
+        deposit: Balance,
+        storage_before: StorageUsage,
+    ) {
+        // record of a new account is charged on registration, not to the event
+        let storage_used = env::storage_usage().saturating_sub(storage_before);
+
+        let owner_id = self.internal_get_event(event_id).owner_id;
+
+        if env::predecessor_account_id() == owner_id {
+            self.internal_deposit_storage(&owner_id, deposit);
+        } else if deposit > 1 {
+            // 1 yocto is required to confirm the call
+            Promise::new(env::predecessor_account_id()).transfer(deposit);
+        }
+
+        self.internal_charge_storage(&owner_id, storage_used);
+
+        self.internal_add_event_storage_usage(event_id, storage_used);
+    }
+
+    pub(crate) fn internal_release_event_storage(
+        &mut self,
+        event_id: &EventId,
+        storage_released: StorageUsage,
+    ) {
+        let mut event = self.internal_get_event(event_id);
+
+        self.internal_release_storage(&event.owner_id, storage_released);
+
+        event.storage_usage = event.storage_usage.saturating_sub(storage_released);
+
+        self.internal_save_event(event);
+    }
+
+    // tracks storage charged to the owner, so it could be moved on the transfer of event
+    pub(crate) fn internal_add_event_storage_usage(
+        &mut self,
+        event_id: &EventId,
+        storage_used: StorageUsage,
+    ) {
+        let mut event = self.internal_get_event(event_id);
+
+        event.storage_usage += storage_used;
+
+        self.internal_save_event(event);
+    }
+
+    pub(crate) fn internal_save_event(&mut self, event: Event) {
+        self.events
+            .insert(&event.id.clone(), &VersionedEvent::from(event));
+    }
+
+    // validates params of the new event & adds it in 'Configuration' status
+    pub(crate) fn internal_create_event(
+        &mut self,
+        owner_id: &AccountId,
+        title: String,
+        start_time: Option<TimestampMs>,
+        end_time: Option<TimestampMs>,
+        metadata: EventMetadata,
+    ) -> EventId {
+        let event_id = self.next_event_id;
+
+        assert_condition(
+            !self.events.contains_key(&event_id),
+            RafflerError::EventAlreadyExists { event_id },
+        );
+
+        let title_length = title.len() as u64;
+
+        assert_condition(
+            self.config.min_title_length <= title_length
+                && title_length <= self.config.max_title_length,
+            RafflerError::InvalidTitleLength {
+                min: self.config.min_title_length,
+                max: self.config.max_title_length,
+            },
+        );
+
+        assert_condition(
+            (start_time.is_some() && end_time.is_some())
+                || (start_time.is_none() && end_time.is_none()),
+            RafflerError::IncompleteEventTime,
+        );
+
+        // in one hour by default
+        let actual_start_time = start_time.unwrap_or(current_time_ms() + 3_600 * 1_000);
+
+        // in one day by default
+        let actual_end_time = end_time.unwrap_or(current_time_ms() + 24 * 3_600 * 1_000);
+
+        assert_condition(
+            actual_start_time < actual_end_time,
+            RafflerError::EndTimeBeforeStartTime,
+        );
+
+        assert_condition(
+            current_time_ms() < actual_start_time,
+            RafflerError::StartTimeInPast,
+        );
+
+        assert_event_metadata(&metadata);
+
+        let event = Event {
+            id: event_id,
+            owner_id: owner_id.clone(),
+            pending_owner_id: None,
+            title,
+            metadata,
+            raffled: false,
+            raffled_at: None,
+            is_visible: false,
+            started_at: actual_start_time,
+            ended_at: actual_end_time,
+            // short versions of "event prizes", "event participants", "event roles",
+            // "event banned" and "event sponsored"
+            prizes: Vector::new(get_event_storage_prefix(b"ep", &event_id)),
+            participants: UnorderedSet::new(get_event_storage_prefix(b"epa", &event_id)),
+            roles: TreeMap::new(get_event_storage_prefix(b"er", &event_id)),
+            banned: UnorderedSet::new(get_event_storage_prefix(b"eb", &event_id)),
+            sponsored: UnorderedSet::new(get_event_storage_prefix(b"es", &event_id)),
+            ticket_price: 0,
+            ticket_sales: 0,
+            min_participants: 0,
+            failed: false,
+            // later changes of fees don't affect already created events
+            fees: self.config.fees.clone(),
+            storage_budget: 0,
+            series_id: None,
+            extension: 0,
+            storage_usage: 0,
+            archived: false,
+        };
+
+        RafflerLogEvent::AddEvent(vec![AddEventLog {
+            event_id,
+            owner_id: event.owner_id.clone(),
+            title: event.title.clone(),
+            started_at: event.started_at,
+            ended_at: event.ended_at,
+        }])
+        .emit();
+
+        self.internal_add_event(event);
+
+        event_id
+    }
+
+    fn internal_add_event(&mut self, event: Event) {
+        let event_id = event.id;
+        let owner_id = event.owner_id.clone();
+
         // insert event
-        self.events.insert(&event.id, &event);
+        self.internal_save_event(event);
 
         self.next_event_id += 1;
 
-        let owner_id = event.owner_id.clone();
+        self.internal_add_owner_event(&owner_id, &event_id);
+    }
 
+    fn internal_add_owner_event(&mut self, owner_id: &AccountId, event_id: &EventId) {
         // add event to owner mapping
-        let mut owner_events = self.events_by_owner.get(&owner_id).unwrap_or_else(|| {
+        let mut owner_events = self.events_by_owner.get(owner_id).unwrap_or_else(|| {
             let mut prefix = Vec::with_capacity(34);
 
-            prefix.extend(b"oe");
+            prefix.extend(b"ot"); // short version of "owner events tree"
             prefix.extend(env::sha256(owner_id.as_bytes()));
 
-            UnorderedSet::new(prefix)
+            TreeMap::new(prefix)
         });
 
-        owner_events.insert(&event.id);
+        owner_events.insert(event_id, &());
+
+        self.events_by_owner.insert(owner_id, &owner_events);
+    }
+
+    fn internal_remove_owner_event(&mut self, owner_id: &AccountId, event_id: &EventId) {
+        let mut owner_events = match self.events_by_owner.get(owner_id) {
+            Some(events) => events,
+            None => return,
+        };
 
-        self.events_by_owner.insert(&owner_id, &owner_events);
+        owner_events.remove(event_id);
+
+        if owner_events.is_empty() {
+            self.events_by_owner.remove(owner_id);
+        } else {
+            self.events_by_owner.insert(owner_id, &owner_events);
+        }
     }
 
-    pub(super) fn internal_add_event_prize(&mut self, event: &mut Event, prize: Prize) {
+    pub(crate) fn internal_set_event_visible(&mut self, mut event: Event) {
+        let event_id = event.id;
+
+        event.is_visible = true;
+
+        // visible events are listed globally
+        self.internal_add_visible_event(&event);
+
+        self.internal_save_event(event);
+
+        RafflerLogEvent::SetEventVisible(vec![SetEventVisibleLog { event_id }]).emit();
+    }
+
+    fn internal_add_visible_event(&mut self, event: &Event) {
+        self.visible_events_by_start
+            .insert(&(event.started_at, event.id), &());
+        self.visible_events_by_end
+            .insert(&(event.ended_at, event.id), &());
+    }
+
+    fn internal_remove_visible_event(&mut self, event: &Event) {
+        self.visible_events_by_start
+            .remove(&(event.started_at, event.id));
+        self.visible_events_by_end
+            .remove(&(event.ended_at, event.id));
+    }
+
+    // the end of visible event is also moved in the index ordered by end time
+    pub(super) fn internal_set_event_end(&mut self, event: &mut Event, ended_at: TimestampMs) {
+        if event.is_visible {
+            self.visible_events_by_end
+                .remove(&(event.ended_at, event.id));
+            self.visible_events_by_end
+                .insert(&(ended_at, event.id), &());
+        }
+
+        event.ended_at = ended_at;
+    }
+
+    pub(super) fn internal_transfer_event_owner(
+        &mut self,
+        mut event: Event,
+        new_owner_id: &AccountId,
+    ) {
+        let event_id = event.id;
+        let previous_owner_id = event.owner_id.clone();
+
+        let storage_before = env::storage_usage();
+
+        event.owner_id = new_owner_id.clone();
+        event.pending_owner_id = None;
+
+        // owner has all permissions, so role isn't needed anymore
+        event.roles.remove(new_owner_id);
+
+        self.internal_save_event(event);
+
+        // move event between owner mappings
+        self.internal_remove_owner_event(&previous_owner_id, &event_id);
+        self.internal_add_owner_event(new_owner_id, &event_id);
+
+        let storage_after = env::storage_usage();
+
+        // storage charged for the event is moved to the new owner along with changes of the transfer
+        let mut event = self.internal_get_event(&event_id);
+
+        self.internal_release_storage(&previous_owner_id, event.storage_usage);
+
+        event.storage_usage = (event.storage_usage + storage_after).saturating_sub(storage_before);
+
+        self.internal_charge_storage(new_owner_id, event.storage_usage);
+
+        self.internal_save_event(event);
+    }
+
+    // validates & adds the prize, returns the amount to be deposited for it (prize + platform fee)
+    pub(super) fn internal_add_near_prize(
+        &mut self,
+        event: Event,
+        amount: U128,
+        quantity: u64,
+    ) -> Balance {
+        assert_condition(
+            (1..=MAX_PRIZE_QUANTITY).contains(&quantity),
+            RafflerError::InvalidPrizeQuantity {
+                max: MAX_PRIZE_QUANTITY,
+            },
+        );
+
+        self.assert_prize_limits(&event, amount, quantity);
+
+        self.internal_push_near_prize(event, amount, quantity)
+    }
+
+    // adds already validated prize, every winner gets the whole amount
+    pub(crate) fn internal_push_near_prize(
+        &mut self,
+        event: Event,
+        amount: U128,
+        quantity: u64,
+    ) -> Balance {
+        let event_id = event.id;
+
+        let (prize_index, total_fee) =
+            self.internal_push_prize(event, PrizeType::NEAR { amount }, quantity);
+
+        RafflerLogEvent::AddNearPrize(vec![AddNearPrizeLog {
+            event_id,
+            prize_index,
+            amount,
+            quantity,
+        }])
+        .emit();
+
+        total_fee
+    }
+
+    // validates & adds the pool which is split among winners, returns the amount to be deposited for it
+    pub(super) fn internal_add_split_pot_prize(
+        &mut self,
+        event: Event,
+        amount: U128,
+        split: PrizeSplit,
+    ) -> Balance {
+        assert_prize_split(&split);
+
+        let quantity = split.winners();
+
+        self.assert_prize_limits(&event, amount, quantity);
+
+        let event_id = event.id;
+
+        let (prize_index, total_fee) = self.internal_push_prize(
+            event,
+            PrizeType::SplitPot {
+                amount,
+                split: split.clone(),
+            },
+            quantity,
+        );
+
+        RafflerLogEvent::AddSplitPotPrize(vec![AddSplitPotPrizeLog {
+            event_id,
+            prize_index,
+            amount,
+            split,
+        }])
+        .emit();
+
+        total_fee
+    }
+
+    fn assert_prize_limits(&self, event: &Event, amount: U128, quantity: u64) {
+        assert_condition(
+            event.prizes.len() < self.config.max_prizes_per_event,
+            RafflerError::TooManyPrizes {
+                max: self.config.max_prizes_per_event,
+            },
+        );
+
+        let winners: u64 = event
+            .prizes
+            .iter()
+            .map(|prize| Prize::from(prize).quantity)
+            .sum();
+
+        assert_condition(
+            winners + quantity <= MAX_WINNERS_PER_EVENT,
+            RafflerError::TooManyWinners {
+                max: MAX_WINNERS_PER_EVENT,
+            },
+        );
+
+        assert_condition(
+            amount.0 >= self.config.min_near_prize_amount.0,
+            RafflerError::PrizeAmountTooSmall {
+                min: self.config.min_near_prize_amount.0,
+            },
+        );
+    }
+
+    // adds the prize & accrues platform fee on its total amount,
+    // returns index of the prize & the amount to be deposited for it (prize + platform fee)
+    fn internal_push_prize(
+        &mut self,
+        event: Event,
+        prize_type: PrizeType,
+        quantity: u64,
+    ) -> (u64, Balance) {
+        let total_amount = get_prize_total_amount(&prize_type, quantity);
+
+        let platform_fee = get_fee_amount(total_amount, event.fees.prize_fee_bps);
+
+        self.accrued_fees += platform_fee;
+
+        let prize_index = event.prizes.len();
+
+        self.internal_add_event_prize(
+            event,
+            Prize {
+                prize_type,
+                quantity,
+                winners: Vec::new(),
+            },
+        );
+
+        (prize_index, total_amount + platform_fee)
+    }
+
+    fn internal_add_event_prize(&mut self, mut event: Event, prize: Prize) {
         // @todo make sure prize with such id doesn't exist
-        event.prizes.push(&prize);
+        event.prizes.push(&VersionedPrize::from(prize));
+
+        self.internal_save_event(event);
+    }
 
-        self.events.insert(&event.id, &event);
+    pub(super) fn internal_set_event_role(
+        &mut self,
+        mut event: Event,
+        account_id: &AccountId,
+        role: Option<EventRole>,
+    ) {
+        match role {
+            Some(r) => event.roles.insert(account_id, &r),
+            None => event.roles.remove(account_id),
+        };
+
+        self.internal_save_event(event);
     }
 
-    pub(super) fn internal_join_event(&mut self, event: &mut Event, participant_id: &AccountId) {
+    pub(super) fn internal_join_event(&mut self, mut event: Event, participant_id: &AccountId) {
+        let event_id = event.id;
+
         // @todo make sure prize with such id doesn't exist
         event.participants.insert(participant_id);
 
-        self.events.insert(&event.id, &event);
+        event.ticket_sales += event.ticket_price;
+
+        self.internal_save_event(event);
 
+        self.internal_add_participant_event(participant_id, &event_id);
+    }
+
+    fn internal_add_participant_event(&mut self, participant_id: &AccountId, event_id: &EventId) {
         // add event to participant mapping
         let mut participant_events = self
             .events_by_participant
@@ -65,65 +636,429 @@ impl Contract {
             .unwrap_or_else(|| {
                 let mut prefix = Vec::with_capacity(34);
 
-                prefix.extend(b"pe"); // short version of "participant events"
+                prefix.extend(b"pt"); // short version of "participant events tree"
                 prefix.extend(env::sha256(participant_id.as_bytes()));
 
-                UnorderedSet::new(prefix)
+                TreeMap::new(prefix)
             });
 
-        participant_events.insert(&event.id);
+        participant_events.insert(event_id, &());
 
         self.events_by_participant
             .insert(participant_id, &participant_events);
     }
 
+    // removes the participant from the event before the draw, refunds the ticket & releases storage
+    pub(super) fn internal_remove_participant(
+        &mut self,
+        event: &mut Event,
+        participant_id: &AccountId,
+    ) {
+        let storage_before = env::storage_usage();
+
+        event.participants.remove(participant_id);
+
+        let was_sponsored = event.sponsored.remove(participant_id);
+
+        self.internal_remove_participant_event(participant_id, &event.id);
+
+        let storage_released = storage_before.saturating_sub(env::storage_usage());
+
+        // storage of sponsored participants was paid from the budget, so it goes back there
+        if was_sponsored {
+            event.storage_budget += get_storage_cost(storage_released);
+        } else {
+            self.internal_release_storage(participant_id, storage_released);
+        }
+
+        if event.ticket_price > 0 {
+            event.ticket_sales -= event.ticket_price;
+
+            Promise::new(participant_id.clone()).transfer(event.ticket_price);
+        }
+    }
+
+    fn internal_remove_participant_event(
+        &mut self,
+        participant_id: &AccountId,
+        event_id: &EventId,
+    ) {
+        // participant of event of the first release might be still in the legacy mapping
+        remove_legacy_entry(legacy_events_by_participant(), participant_id, event_id);
+
+        let mut participant_events = match self.events_by_participant.get(participant_id) {
+            Some(events) => events,
+            None => return,
+        };
+
+        participant_events.remove(event_id);
+
+        if participant_events.is_empty() {
+            self.events_by_participant.remove(participant_id);
+        } else {
+            self.events_by_participant
+                .insert(participant_id, &participant_events);
+        }
+    }
+
+    // measures how much storage takes joining the event by account with the longest possible id,
+    // who hasn't participated in any event before & is sponsored by the event
+    pub(crate) fn measure_join_storage_usage(&mut self) {
+        let storage_before = env::storage_usage();
+
+        let tmp_account_id: AccountId = "a".repeat(64).parse().unwrap();
+        let tmp_event_id = EventId::MAX;
+
+        let mut participants = UnorderedSet::new(get_event_storage_prefix(b"epa", &tmp_event_id));
+        let mut sponsored = UnorderedSet::new(get_event_storage_prefix(b"es", &tmp_event_id));
+
+        participants.insert(&tmp_account_id);
+        sponsored.insert(&tmp_account_id);
+
+        self.internal_add_participant_event(&tmp_account_id, &tmp_event_id);
+
+        self.join_storage_usage = env::storage_usage() - storage_before;
+
+        participants.clear();
+        sponsored.clear();
+
+        self.internal_remove_participant_event(&tmp_account_id, &tmp_event_id);
+    }
+
+    pub(crate) fn internal_join_storage_cost(&self) -> Balance {
+        get_storage_cost(self.join_storage_usage)
+    }
+
     pub(super) fn internal_raffle_prizes(&mut self, event_id: &EventId) -> Vec<EventPrize> {
         let mut event = self.internal_get_event(event_id);
 
-        let prizes = (0..event.prizes.len())
-            .map(|prize_index| self.internal_raffle_prize(&mut event, prize_index))
+        let prizes: Vec<EventPrize> = (0..event.prizes.len())
+            .flat_map(|prize_index| self.internal_raffle_prize(&mut event, prize_index))
+            .collect();
+
+        let winners = prizes
+            .iter()
+            .map(|event_prize| PrizeWinnerLog {
+                prize_index: event_prize.prize_index,
+                slot_index: event_prize.slot_index,
+                account_id: Prize::from(event.prizes.get(event_prize.prize_index).unwrap()).winners
+                    [event_prize.slot_index as usize]
+                    .account_id
+                    .clone(),
+            })
             .collect();
 
+        RafflerLogEvent::RaffleEventPrizes(vec![RaffleEventPrizesLog {
+            event_id: *event_id,
+            winners,
+        }])
+        .emit();
+
         event.raffled = true;
+        event.raffled_at = Some(current_time_ms());
 
-        self.events.insert(&event.id, &event);
+        self.internal_pay_out_ticket_sales(&mut event);
+
+        self.internal_refund_storage_budget(&mut event);
+
+        self.internal_save_event(event);
 
         prizes
     }
 
-    fn internal_raffle_prize(&mut self, event: &mut Event, prize_index: u64) -> EventPrize {
-        let winner_id = get_random_event_participant_id(&event);
+    // the event is cancelled instead of the raffle, prizes are returned to the owner
+    // & tickets to participants, their amount is less than 'MAX_MIN_PARTICIPANTS'
+    pub(super) fn internal_fail_event(&mut self, event_id: &EventId) {
+        let mut event = self.internal_get_event(event_id);
+
+        let prizes_amount: Balance = event
+            .prizes
+            .iter()
+            .map(Prize::from)
+            .map(|prize| get_prize_total_amount(&prize.prize_type, prize.quantity))
+            .sum();
 
-        // update winner's account
-        let mut prize = event.prizes.get(prize_index.clone()).unwrap();
+        if prizes_amount > 0 {
+            Promise::new(event.owner_id.clone()).transfer(prizes_amount);
+        }
 
-        prize.winner_account_id = Some(winner_id.clone());
+        if event.ticket_price > 0 {
+            for participant_id in event.participants.iter() {
+                Promise::new(participant_id).transfer(event.ticket_price);
+            }
+        }
 
-        event.prizes.replace(prize_index.clone(), &prize);
+        RafflerLogEvent::FailEvent(vec![FailEventLog {
+            event_id: *event_id,
+            participants_amount: event.participants.len(),
+            min_participants: event.min_participants,
+        }])
+        .emit();
 
-        let event_prize = EventPrize {
-            event_id: event.id,
-            prize_index: prize_index.clone(),
-        };
+        event.raffled = true;
+        event.raffled_at = Some(current_time_ms());
+        event.failed = true;
+        event.ticket_sales = 0;
+
+        self.internal_refund_storage_budget(&mut event);
+
+        self.internal_save_event(event);
+    }
+
+    fn internal_pay_out_ticket_sales(&mut self, event: &mut Event) {
+        if event.ticket_sales == 0 {
+            return;
+        }
+
+        let platform_fee = get_fee_amount(event.ticket_sales, event.fees.ticket_fee_bps);
+
+        self.accrued_fees += platform_fee;
+
+        Promise::new(event.owner_id.clone()).transfer(event.ticket_sales - platform_fee);
 
-        // add prize to unclaimed set
+        event.ticket_sales = 0;
+    }
+
+    // sponsored storage which wasn't spent on joins is returned to the owner
+    fn internal_refund_storage_budget(&mut self, event: &mut Event) {
+        if event.storage_budget == 0 {
+            return;
+        }
+
+        Promise::new(event.owner_id.clone()).transfer(event.storage_budget);
+
+        event.storage_budget = 0;
+    }
+
+    // raffles distinct winners for every slot of the prize, slots which couldn't be filled
+    // (there are less participants than the quantity) and rounding dust of split pot
+    // are refunded to the owner right away
+    fn internal_raffle_prize(&mut self, event: &mut Event, prize_index: u64) -> Vec<EventPrize> {
+        let event_id = event.id;
+
+        let mut prize = Prize::from(event.prizes.get(prize_index).unwrap());
+
+        let winner_ids = get_random_event_participant_ids(event, prize_index, prize.quantity);
+
+        prize.winners = winner_ids
+            .iter()
+            .zip(get_prize_slot_amounts(&prize))
+            .map(|(account_id, amount)| PrizeWinner {
+                account_id: account_id.clone(),
+                amount: U128(amount),
+                claimed: false,
+                claimed_at: None,
+            })
+            .collect();
+
+        let won_amount: Balance = prize.winners.iter().map(|winner| winner.amount.0).sum();
+
+        let refund = get_prize_total_amount(&prize.prize_type, prize.quantity) - won_amount;
+
+        if refund > 0 {
+            Promise::new(event.owner_id.clone()).transfer(refund);
+        }
+
+        event
+            .prizes
+            .replace(prize_index, &VersionedPrize::from(prize));
+
+        winner_ids
+            .into_iter()
+            .enumerate()
+            .map(|(slot_index, winner_id)| {
+                let event_prize = EventPrize {
+                    event_id,
+                    prize_index,
+                    slot_index: slot_index as u64,
+                };
+
+                self.internal_add_unclaimed_prize(&winner_id, &event_prize);
+
+                event_prize
+            })
+            .collect()
+    }
+
+    fn internal_add_unclaimed_prize(&mut self, account_id: &AccountId, prize: &EventPrize) {
         let mut unclaimed_prizes = self
             .unclaimed_prizes_by_account
-            .get(&winner_id.clone())
+            .get(account_id)
             .unwrap_or_else(|| {
                 let mut prefix = Vec::with_capacity(34);
 
-                prefix.extend(b"up"); // meaning "unclaimed prizes"
-                prefix.extend(env::sha256(winner_id.clone().as_bytes()));
+                prefix.extend(b"ut"); // meaning "unclaimed prizes tree"
+                prefix.extend(env::sha256(account_id.as_bytes()));
 
-                UnorderedSet::new(prefix)
+                TreeMap::new(prefix)
             });
 
-        unclaimed_prizes.insert(&event_prize);
+        unclaimed_prizes.insert(prize, &());
 
         self.unclaimed_prizes_by_account
-            .insert(&winner_id.clone(), &unclaimed_prizes);
+            .insert(account_id, &unclaimed_prizes);
+    }
+
+    // settles prizes & releases up to 'limit' participants, the event is replaced
+    // with its summary once all of them are released, returns whether it's done
+    pub(super) fn internal_archive_event(&mut self, mut event: Event, limit: u64) -> bool {
+        let event_id = event.id;
+        let owner_id = event.owner_id.clone();
+
+        if !event.archived {
+            let storage_before = env::storage_usage();
+
+            self.internal_settle_expired_prizes(&event);
 
-        event_prize
+            let summary = EventSummary {
+                id: event_id,
+                owner_id: owner_id.clone(),
+                title: event.title.clone(),
+                started_at: event.started_at,
+                ended_at: event.ended_at,
+                raffled_at: event.raffled_at,
+                participants_amount: event.participants.len(),
+                prizes: event
+                    .prizes
+                    .iter()
+                    .map(|prize| VersionedPrize::from(Prize::from(prize)))
+                    .collect(),
+                series_id: event.series_id,
+                ticket_price: event.ticket_price,
+                fees: event.fees.clone(),
+            };
+
+            self.archived_events.insert(&event_id, &summary);
+
+            // archived events aren't listed anymore, only found by id
+            if event.is_visible {
+                self.internal_remove_visible_event(&event);
+            }
+
+            event.archived = true;
+
+            let storage_after = env::storage_usage();
+
+            // owners of legacy events might not be registered, so the summary is on the contract
+            if storage_after > storage_before && self.storage_accounts.contains_key(&owner_id) {
+                self.internal_charge_storage(&owner_id, storage_after - storage_before);
+
+                event.storage_usage += storage_after - storage_before;
+            } else {
+                self.internal_release_storage(
+                    &owner_id,
+                    storage_before.saturating_sub(storage_after),
+                );
+
+                event.storage_usage = event
+                    .storage_usage
+                    .saturating_sub(storage_before.saturating_sub(storage_after));
+            }
+        }
+
+        for _ in 0..limit {
+            let participants_amount = event.participants.len();
+
+            if participants_amount == 0 {
+                break;
+            }
+
+            let participant_id = event
+                .participants
+                .as_vector()
+                .get(participants_amount - 1)
+                .unwrap();
+
+            let storage_before = env::storage_usage();
+
+            event.participants.remove(&participant_id);
+
+            let was_sponsored = event.sponsored.remove(&participant_id);
+
+            self.internal_remove_participant_event(&participant_id, &event_id);
+
+            // storage of sponsored participants was paid from the budget, which leftovers
+            // were refunded on raffle, so it isn't released to any storage account
+            if !was_sponsored {
+                self.internal_release_storage(
+                    &participant_id,
+                    storage_before - env::storage_usage(),
+                );
+            }
+        }
+
+        if !event.participants.is_empty() {
+            self.internal_save_event(event);
+
+            return false;
+        }
+
+        let storage_before = env::storage_usage();
+
+        event.prizes.clear();
+        event.roles.clear();
+        event.banned.clear();
+
+        self.events.remove(&event_id);
+
+        // prizes, roles & bans are charged to the owner even if they were added by role holders
+        self.internal_release_storage(&owner_id, storage_before - env::storage_usage());
+
+        true
+    }
+
+    // prizes which weren't claimed are returned to the owner
+    fn internal_settle_expired_prizes(&mut self, event: &Event) {
+        // prizes of failed event were refunded already
+        if event.failed {
+            return;
+        }
+
+        for prize_index in 0..event.prizes.len() {
+            let prize = Prize::from(event.prizes.get(prize_index).unwrap());
+
+            let mut unclaimed_amount: Balance = 0;
+
+            for (slot_index, winner) in prize.winners.iter().enumerate() {
+                if winner.claimed {
+                    continue;
+                }
+
+                self.internal_remove_unclaimed_prize(
+                    &winner.account_id,
+                    &EventPrize {
+                        event_id: event.id,
+                        prize_index,
+                        slot_index: slot_index as u64,
+                    },
+                );
+
+                unclaimed_amount += winner.amount.0;
+            }
+
+            if unclaimed_amount > 0 {
+                Promise::new(event.owner_id.clone()).transfer(unclaimed_amount);
+            }
+        }
+    }
+
+    pub(super) fn internal_remove_unclaimed_prize(
+        &mut self,
+        account_id: &AccountId,
+        prize: &EventPrize,
+    ) {
+        let mut unclaimed_prizes = match self.unclaimed_prizes_by_account.get(account_id) {
+            Some(prizes) => prizes,
+            None => return,
+        };
+
+        unclaimed_prizes.remove(prize);
+
+        if unclaimed_prizes.is_empty() {
+            self.unclaimed_prizes_by_account.remove(account_id);
+        } else {
+            self.unclaimed_prizes_by_account
+                .insert(account_id, &unclaimed_prizes);
+        }
     }
 }
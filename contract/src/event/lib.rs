@@ -1,9 +1,13 @@
-use near_sdk::collections::{UnorderedSet, Vector};
 use near_sdk::json_types::U128;
-use near_sdk::{env, Balance, Promise};
+use near_sdk::{env, AccountId, Balance, Promise};
 
 use super::types::*;
-use super::utils::{assert_event_owner, assert_event_status};
+use super::utils::{
+    assert_event_metadata, assert_event_owner, assert_event_permission, assert_event_status,
+    get_event_status,
+};
+use crate::errors::RafflerError;
+use crate::logs::types::*;
 use crate::types::TimestampMs;
 use crate::utils::*;
 use crate::*;
@@ -19,349 +23,2569 @@ impl Contract {
         title: String,
         start_time: Option<TimestampMs>,
         end_time: Option<TimestampMs>,
+        metadata: Option<EventMetadata>,
     ) -> EventId {
+        self.assert_not_paused();
+
+        self.assert_not_blocked(&env::predecessor_account_id());
+
+        self.assert_creator_allowed(&env::predecessor_account_id());
+
         assert_at_least_one_yocto();
 
-        let event_id = self.next_event_id.clone();
+        let storage_before = env::storage_usage();
 
-        assert_condition(
-            self.events.contains_key(&event_id) == false,
-            "Couldn't add_event because event_id is already exist",
+        let event_id = self.internal_create_event(
+            &env::predecessor_account_id(),
+            title,
+            start_time,
+            end_time,
+            metadata.unwrap_or_default(),
         );
 
-        assert_condition(
-            4 <= title.len() && title.len() <= 64,
-            "'title' should be between 4 and 64 symbols",
-        );
+        self.internal_pay_for_event_storage(&event_id, env::attached_deposit(), storage_before);
 
-        assert_condition(
-            (start_time.is_some() && end_time.is_some())
-                || (start_time.is_none() && end_time.is_none()),
-            "Both 'start_time' and 'end_time' either must be present or absent",
-        );
+        event_id
+    }
+    #[witgen]
+    pub fn set_event_time(
+        &mut self,
+        event_id: EventId,
+        start_time: TimestampMs,
+        end_time: TimestampMs,
+    ) {
+        self.assert_not_paused();
+
+        let mut event = self.internal_get_event(&event_id);
+
+        assert_event_permission(&event, EventPermission::Configure);
 
-        // in one hour by default
-        let actual_start_time = start_time.unwrap_or(current_time_ms() + 3_600 * 1_000);
+        assert_event_status(&event, EventStatus::Configuration);
 
-        // in one day by default
-        let actual_end_time = end_time.unwrap_or(current_time_ms() + 24 * 3_600 * 1_000);
+        assert_condition(start_time < end_time, RafflerError::EndTimeBeforeStartTime);
 
         assert_condition(
-            actual_start_time < actual_end_time,
-            "'end_time' must be bigger than 'start_time'",
+            current_time_ms() < start_time,
+            RafflerError::StartTimeInPast,
         );
 
+        event.started_at = start_time;
+        event.ended_at = end_time;
+
+        self.internal_save_event(event);
+
+        RafflerLogEvent::SetEventTime(vec![SetEventTimeLog {
+            event_id,
+            started_at: start_time,
+            ended_at: end_time,
+        }])
+        .emit();
+    }
+
+    #[witgen]
+    pub fn extend_event(&mut self, event_id: EventId, end_time: TimestampMs) {
+        self.assert_not_paused();
+
+        let mut event = self.internal_get_event(&event_id);
+
+        assert_event_permission(&event, EventPermission::Configure);
+
+        assert_event_status(&event, EventStatus::Active);
+
+        assert_condition(event.ended_at < end_time, RafflerError::EndTimeNotExtended);
+
+        let extension = event.extension + (end_time - event.ended_at);
+
         assert_condition(
-            current_time_ms() < actual_start_time,
-            "'start_time' couldn't be set to value in the past",
+            extension <= self.config.max_event_extension,
+            RafflerError::EventExtensionTooLong {
+                max: self.config.max_event_extension,
+            },
         );
 
-        let mut default_prizes_prefix = Vec::with_capacity(34);
+        self.internal_set_event_end(&mut event, end_time);
 
-        default_prizes_prefix.extend(b"ep"); // short version of "event prizes"
-        default_prizes_prefix.extend(env::sha256(&event_id.to_be_bytes()));
+        event.extension = extension;
 
-        let mut default_participants_prefix = Vec::with_capacity(35);
+        self.internal_save_event(event);
 
-        default_participants_prefix.extend(b"epa"); // short version of "event participants"
-        default_participants_prefix.extend(env::sha256(&event_id.to_be_bytes()));
+        RafflerLogEvent::ExtendEvent(vec![ExtendEventLog {
+            event_id,
+            ended_at: end_time,
+        }])
+        .emit();
+    }
 
-        let event = Event {
-            id: event_id.clone(),
-            owner_id: env::predecessor_account_id(),
-            title,
-            raffled: false,
-            is_visible: false,
-            started_at: actual_start_time,
-            ended_at: actual_end_time,
-            prizes: Vector::new(default_prizes_prefix),
-            participants: UnorderedSet::new(default_participants_prefix),
-        };
+    /// Ends the active event right away, so prizes could be raffled
+    #[witgen]
+    pub fn end_event(&mut self, event_id: EventId) {
+        self.assert_not_paused();
 
-        let storage_before = env::storage_usage();
+        let mut event = self.internal_get_event(&event_id);
 
-        self.internal_add_event(event);
+        assert_event_permission(&event, EventPermission::Configure);
 
-        let storage_after = env::storage_usage();
+        assert_event_status(&event, EventStatus::Active);
 
-        let storage_used = storage_after - storage_before;
+        let ended_at = current_time_ms();
 
-        assert_enough_attached_deposit(storage_used);
+        self.internal_set_event_end(&mut event, ended_at);
 
-        refund_deposit(storage_used);
+        self.internal_save_event(event);
 
-        event_id
+        RafflerLogEvent::EndEvent(vec![EndEventLog { event_id, ended_at }]).emit();
     }
 
     #[witgen]
-    pub fn set_event_time(
-        &mut self,
-        event_id: EventId,
-        start_time: TimestampMs,
-        end_time: TimestampMs,
-    ) {
-        let mut event = self.internal_get_event(&event_id);
+    #[payable]
+    pub fn set_event_visible(&mut self, event_id: EventId) {
+        self.assert_not_paused();
 
-        assert_event_owner(&event);
+        let event = self.internal_get_event(&event_id);
+
+        assert_event_permission(&event, EventPermission::Configure);
 
         assert_event_status(&event, EventStatus::Configuration);
 
         assert_condition(
-            start_time < end_time,
-            "'end_time' must be bigger than 'start_time'",
+            event.prizes.len() >= 1,
+            RafflerError::EventWithoutPrizes { event_id },
         );
 
-        assert_condition(
-            current_time_ms() < start_time,
-            "'start_time' couldn't be set to value in the past",
-        );
+        let storage_before = env::storage_usage();
 
-        event.started_at = start_time;
-        event.ended_at = end_time;
+        self.internal_set_event_visible(event);
 
-        self.events.insert(&event_id, &event);
+        self.internal_pay_for_event_storage(&event_id, env::attached_deposit(), storage_before);
     }
 
     #[witgen]
-    pub fn set_event_visible(&mut self, event_id: EventId) {
+    pub fn set_event_ticket_price(&mut self, event_id: EventId, ticket_price: U128) {
+        self.assert_not_paused();
+
         let mut event = self.internal_get_event(&event_id);
 
-        assert_event_owner(&event);
+        assert_event_permission(&event, EventPermission::Configure);
+
+        assert_event_status(&event, EventStatus::Configuration);
+
+        event.ticket_price = ticket_price.0;
+
+        self.internal_save_event(event);
+    }
+
+    #[witgen]
+    pub fn set_event_min_participants(&mut self, event_id: EventId, min_participants: u64) {
+        self.assert_not_paused();
+
+        let mut event = self.internal_get_event(&event_id);
+
+        assert_event_permission(&event, EventPermission::Configure);
 
         assert_event_status(&event, EventStatus::Configuration);
 
         assert_condition(
-            event.prizes.len() >= 1,
-            "Couldn't set event visible since there're no prizes",
+            min_participants <= MAX_MIN_PARTICIPANTS,
+            RafflerError::InvalidMinParticipants {
+                max: MAX_MIN_PARTICIPANTS,
+            },
         );
 
-        event.is_visible = true;
+        event.min_participants = min_participants;
 
-        self.events.insert(&event_id, &event);
+        self.internal_save_event(event);
     }
 
     #[witgen]
     #[payable]
-    pub fn add_near_prize(&mut self, event_id: EventId, amount: U128) {
-        assert_at_least_one_yocto();
+    pub fn set_event_metadata(&mut self, event_id: EventId, metadata: EventMetadata) {
+        self.assert_not_paused();
 
         let mut event = self.internal_get_event(&event_id);
 
-        assert_event_owner(&event);
+        assert_event_permission(&event, EventPermission::Configure);
 
         assert_event_status(&event, EventStatus::Configuration);
 
-        assert_condition(event.prizes.len() <= 5, "Event could have max 5 prizes");
-
-        assert_condition(
-            amount.0 >= MIN_NEAR_PRIZE_AMOUNT,
-            format!(
-                "Prize amount couldn't be less than {} yoctoNear",
-                MIN_NEAR_PRIZE_AMOUNT
-            ),
-        );
+        assert_event_metadata(&metadata);
 
-        let prize = Prize {
-            prize_type: PrizeType::NEAR { amount },
-            winner_account_id: None,
-            claimed: false,
-        };
+        event.metadata = metadata;
 
         let storage_before = env::storage_usage();
 
-        self.internal_add_event_prize(&mut event, prize);
+        self.internal_save_event(event);
 
         let storage_after = env::storage_usage();
 
-        let storage_cost = get_storage_cost(storage_after - storage_before);
+        if storage_after >= storage_before {
+            self.internal_pay_for_event_storage(&event_id, env::attached_deposit(), storage_before);
+        } else {
+            // the deposit is handled as for grown metadata, nothing is charged
+            self.internal_pay_for_event_storage(&event_id, env::attached_deposit(), storage_after);
+
+            // the owner has paid for the record of event
+            self.internal_release_event_storage(&event_id, storage_before - storage_after);
+        }
+    }
+
+    #[witgen]
+    #[payable]
+    pub fn fund_event_storage(&mut self, event_id: EventId) {
+        self.assert_not_paused();
+
+        assert_at_least_one_yocto();
+
+        let mut event = self.internal_get_event(&event_id);
 
-        // storage + amount of prize
-        let total_fee = storage_cost + Balance::from(amount);
+        // leftovers are refunded to the owner, so nobody else could fund the budget
+        assert_event_owner(&event);
 
+        // the budget is only spent on joins, so it's funded until the event ends
         assert_condition(
-            env::attached_deposit() >= total_fee,
-            format!("You should attach at least {} yoctoNear", total_fee),
+            !event.failed
+                && matches!(
+                    get_event_status(&event),
+                    EventStatus::Configuration | EventStatus::Visible | EventStatus::Active
+                ),
+            RafflerError::EventAlreadyEnded { event_id },
         );
 
-        let refund = env::attached_deposit() - total_fee;
+        // whole deposit goes to the budget, leftovers are refunded to the owner at raffle time
+        event.storage_budget += env::attached_deposit();
 
-        if refund > 1 {
-            Promise::new(env::predecessor_account_id()).transfer(refund);
-        }
+        self.internal_save_event(event);
     }
 
     #[witgen]
     #[payable]
-    pub fn join_event(&mut self, event_id: EventId) {
+    pub fn add_near_prize(&mut self, event_id: EventId, amount: U128, quantity: Option<u64>) {
+        self.assert_not_paused();
+
         assert_at_least_one_yocto();
 
-        let mut event = self.internal_get_event(&event_id);
+        let event = self.internal_get_event(&event_id);
+
+        assert_event_permission(&event, EventPermission::Configure);
+
+        assert_event_status(&event, EventStatus::Configuration);
+
+        let storage_before = env::storage_usage();
+
+        // amount of prize for every winner + platform fee
+        let total_fee = self.internal_add_near_prize(event, amount, quantity.unwrap_or(1));
 
         assert_condition(
-            &event.owner_id != &env::predecessor_account_id(),
-            "Owner can't participate his own events",
+            env::attached_deposit() >= total_fee,
+            RafflerError::NotEnoughDeposit {
+                required: total_fee,
+            },
         );
 
-        assert_event_status(&event, EventStatus::Active);
+        // the rest of deposit goes to the storage balance of the owner
+        self.internal_pay_for_event_storage(
+            &event_id,
+            env::attached_deposit() - total_fee,
+            storage_before,
+        );
+    }
+
+    /// Adds the pool which is shared among winners by the split
+    #[witgen]
+    #[payable]
+    pub fn add_split_pot_prize(&mut self, event_id: EventId, amount: U128, split: PrizeSplit) {
+        self.assert_not_paused();
+
+        assert_at_least_one_yocto();
+
+        let event = self.internal_get_event(&event_id);
+
+        assert_event_permission(&event, EventPermission::Configure);
+
+        assert_event_status(&event, EventStatus::Configuration);
+
+        let storage_before = env::storage_usage();
+
+        // amount of the pool + platform fee
+        let total_fee = self.internal_add_split_pot_prize(event, amount, split);
 
         assert_condition(
-            !event.participants.contains(&env::predecessor_account_id()),
-            "You're already participating in this event",
+            env::attached_deposit() >= total_fee,
+            RafflerError::NotEnoughDeposit {
+                required: total_fee,
+            },
+        );
+
+        // the rest of deposit goes to the storage balance of the owner
+        self.internal_pay_for_event_storage(
+            &event_id,
+            env::attached_deposit() - total_fee,
+            storage_before,
         );
+    }
+
+    /// Creates the event with prizes & optionally publishes it, the attached deposit
+    /// should cover prizes with platform fees & storage, the excess is refunded
+    #[witgen]
+    #[payable]
+    pub fn create_event_full(
+        &mut self,
+        title: String,
+        start_time: Option<TimestampMs>,
+        end_time: Option<TimestampMs>,
+        metadata: Option<EventMetadata>,
+        prizes: Vec<PrizeDefinition>,
+        publish: bool,
+    ) -> EventId {
+        self.assert_not_paused();
+
+        self.assert_not_blocked(&env::predecessor_account_id());
+
+        self.assert_creator_allowed(&env::predecessor_account_id());
+
+        assert_at_least_one_yocto();
+
+        let owner_id = env::predecessor_account_id();
 
         let storage_before = env::storage_usage();
 
-        self.internal_join_event(&mut event, &env::predecessor_account_id());
+        let event_id = self.internal_create_event(
+            &owner_id,
+            title,
+            start_time,
+            end_time,
+            metadata.unwrap_or_default(),
+        );
 
-        let storage_after = env::storage_usage();
+        let mut total_fee: Balance = 0;
+
+        for prize in prizes.into_iter() {
+            let event = self.internal_get_event(&event_id);
+
+            total_fee += match prize.prize_type {
+                PrizeType::NEAR { amount } => {
+                    self.internal_add_near_prize(event, amount, prize.quantity.unwrap_or(1))
+                }
+                PrizeType::SplitPot { amount, split } => {
+                    self.internal_add_split_pot_prize(event, amount, split)
+                }
+            };
+        }
+
+        if publish {
+            let event = self.internal_get_event(&event_id);
+
+            assert_condition(
+                !event.prizes.is_empty(),
+                RafflerError::EventWithoutPrizes { event_id },
+            );
+
+            self.internal_set_event_visible(event);
+        }
+
+        let storage_cost = get_storage_cost(env::storage_usage() - storage_before);
+
+        // available storage balance is spent first, new accounts pay for registration as well
+        let storage_deposit = match self.storage_accounts.get(&owner_id) {
+            Some(account_storage) => {
+                storage_cost.saturating_sub(account_storage.total - account_storage.used)
+            }
+            None => storage_cost + self.internal_storage_balance_min(),
+        };
+
+        let required_deposit = total_fee + storage_deposit;
 
-        let storage_used = storage_after - storage_before;
+        assert_condition(
+            env::attached_deposit() >= required_deposit,
+            RafflerError::NotEnoughDeposit {
+                required: required_deposit,
+            },
+        );
+
+        self.internal_pay_for_event_storage(&event_id, storage_deposit, storage_before);
+
+        let refund = env::attached_deposit() - required_deposit;
 
-        assert_enough_attached_deposit(storage_used);
+        if refund > 0 {
+            Promise::new(owner_id).transfer(refund);
+        }
 
-        refund_deposit(storage_used);
+        event_id
     }
 
     #[witgen]
     #[payable]
-    pub fn raffle_event_prizes(&mut self, event_id: EventId) -> Vec<EventPrize> {
-        assert_at_least_one_yocto();
+    pub fn clone_event(
+        &mut self,
+        source_event_id: EventId,
+        start_time: TimestampMs,
+        end_time: TimestampMs,
+    ) -> EventId {
+        self.assert_not_paused();
 
-        let event = self.internal_get_event(&event_id);
+        self.assert_not_blocked(&env::predecessor_account_id());
 
-        assert_event_owner(&event);
+        self.assert_creator_allowed(&env::predecessor_account_id());
 
-        assert_event_status(&event, EventStatus::Raffling);
+        assert_at_least_one_yocto();
+
+        let source_event = self.internal_get_event(&source_event_id);
+
+        assert_event_owner(&source_event);
 
         let storage_before = env::storage_usage();
 
-        let prizes = self.internal_raffle_prizes(&event.id);
+        let event_id = self.internal_create_event(
+            &source_event.owner_id,
+            source_event.title.clone(),
+            Some(start_time),
+            Some(end_time),
+            source_event.metadata.clone(),
+        );
 
-        let storage_after = env::storage_usage();
+        let mut event = self.internal_get_event(&event_id);
 
-        let storage_used = storage_after - storage_before;
+        event.ticket_price = source_event.ticket_price;
+        event.min_participants = source_event.min_participants;
 
-        assert_enough_attached_deposit(storage_used);
+        self.internal_save_event(event);
 
-        refund_deposit(storage_used);
+        // prizes are deposited again, fees are charged by the current config
+        let mut total_fee: Balance = 0;
 
-        prizes
+        for prize in source_event.prizes.iter().map(Prize::from) {
+            let event = self.internal_get_event(&event_id);
+
+            total_fee += match prize.prize_type {
+                PrizeType::NEAR { amount } => {
+                    self.internal_add_near_prize(event, amount, prize.quantity)
+                }
+                PrizeType::SplitPot { amount, split } => {
+                    self.internal_add_split_pot_prize(event, amount, split)
+                }
+            };
+        }
+
+        assert_condition(
+            env::attached_deposit() >= total_fee,
+            RafflerError::NotEnoughDeposit {
+                required: total_fee,
+            },
+        );
+
+        // the rest of deposit goes to the storage balance
+        self.internal_pay_for_event_storage(
+            &event_id,
+            env::attached_deposit() - total_fee,
+            storage_before,
+        );
+
+        event_id
     }
 
     #[witgen]
-    pub fn claim_prize(&mut self, prize: EventPrize) {
-        let mut unclaimed_prizes = self
-            .unclaimed_prizes_by_account
-            .get(&env::predecessor_account_id())
-            // we don't need here to build prefix because this is view method
-            .unwrap_or(UnorderedSet::new(Vec::new()));
+    #[payable]
+    pub fn join_event(&mut self, event_id: EventId) {
+        self.assert_not_paused();
+
+        self.assert_not_blocked(&env::predecessor_account_id());
+
+        assert_at_least_one_yocto();
+
+        let event = self.internal_get_event(&event_id);
 
         assert_condition(
-            unclaimed_prizes.contains(&prize),
-            "Couldn't claim prize since it doesn't exist",
+            event.owner_id != env::predecessor_account_id()
+                && event.pending_owner_id.as_ref() != Some(&env::predecessor_account_id()),
+            RafflerError::OwnerCantParticipate,
         );
 
-        let mut event = self.internal_get_event(&prize.event_id);
+        assert_event_status(&event, EventStatus::Active);
 
-        assert_event_status(&event, EventStatus::Claiming);
+        assert_condition(
+            !event.participants.contains(&env::predecessor_account_id()),
+            RafflerError::AlreadyParticipating { event_id },
+        );
 
         assert_condition(
-            prize.prize_index < event.prizes.len(),
-            "Couldn't claim prize since it doesn't exist",
+            !event.banned.contains(&env::predecessor_account_id()),
+            RafflerError::BannedFromEvent { event_id },
+        );
+
+        // role holders could raffle or moderate the event, so they're not allowed to win it
+        assert_condition(
+            event.roles.get(&env::predecessor_account_id()).is_none(),
+            RafflerError::RoleHolderCantParticipate,
+        );
+
+        let ticket_price = event.ticket_price;
+
+        assert_condition(
+            env::attached_deposit() >= ticket_price,
+            RafflerError::NotEnoughDeposit {
+                required: ticket_price,
+            },
         );
 
-        unclaimed_prizes.remove(&prize);
+        let join_storage_cost = self.internal_join_storage_cost();
+
+        // sponsored by the budget of event while it covers the measured (worst case) join,
+        // only the storage actually used by the join is debited from the budget
+        if event.storage_budget >= join_storage_cost {
+            let storage_before = env::storage_usage();
+
+            self.internal_join_event(event, &env::predecessor_account_id());
 
-        self.unclaimed_prizes_by_account
-            .insert(&env::predecessor_account_id(), &unclaimed_prizes);
+            let mut event = self.internal_get_event(&event_id);
 
-        let mut actual_prize = event.prizes.get(prize.prize_index).unwrap();
+            event.sponsored.insert(&env::predecessor_account_id());
 
-        actual_prize.claimed = true;
+            let storage_cost = get_storage_cost(env::storage_usage() - storage_before);
 
-        event.prizes.replace(prize.prize_index, &actual_prize);
+            event.storage_budget -= storage_cost.min(event.storage_budget);
 
-        self.events.insert(&event.id, &event);
+            self.internal_save_event(event);
 
-        match actual_prize.prize_type {
-            PrizeType::NEAR { amount } => {
-                Promise::new(env::predecessor_account_id()).transfer(amount.0)
+            let refund = env::attached_deposit() - ticket_price;
+
+            if refund > 1 {
+                Promise::new(env::predecessor_account_id()).transfer(refund);
             }
-        };
+        } else {
+            let storage_before = env::storage_usage();
+
+            self.internal_join_event(event, &env::predecessor_account_id());
+
+            // the rest of deposit goes to the storage balance
+            self.internal_pay_for_storage(
+                &env::predecessor_account_id(),
+                env::attached_deposit() - ticket_price,
+                storage_before,
+            );
+        }
+
+        RafflerLogEvent::JoinEvent(vec![JoinEventLog {
+            event_id,
+            account_id: env::predecessor_account_id(),
+        }])
+        .emit();
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::event::types::EventPrize;
+    /// Bans the account from the event before the draw, the participant is removed
+    /// with the ticket refunded, banned accounts couldn't join the event
+    #[witgen]
+    #[payable]
+    pub fn ban_event_participant(&mut self, event_id: EventId, account_id: AccountId) {
+        self.assert_not_paused();
 
-    use super::Contract;
-    use near_sdk::json_types::U128;
-    use near_sdk::test_utils::VMContextBuilder;
-    use near_sdk::testing_env;
-    use near_sdk::MockedBlockchain;
+        assert_at_least_one_yocto();
 
-    const CURRENT_TIME: u64 = 1_000_000;
-    const START_TIME: u64 = 2_000_000;
-    const ACTIVE_TIME: u64 = 3_000_000;
-    const END_TIME: u64 = 4_000_000;
-    const RAFFLE_TIME: u64 = 5_000_000;
-    const CLAIM_TIME: u64 = 6_000_000;
+        let mut event = self.internal_get_event(&event_id);
 
-    #[test]
-    fn pass_add_two_events_with_diff_ids() {
-        let mut contract = Contract::new();
+        assert_event_permission(&event, EventPermission::Moderate);
 
-        let mut context = VMContextBuilder::new();
+        assert_condition(
+            !event.raffled,
+            RafflerError::EventAlreadyRaffled { event_id },
+        );
 
-        context.attached_deposit(2_000_000_000_000_000_000_000_000);
-        context.predecessor_account_id("owner".try_into().unwrap());
+        assert_condition(
+            !event.banned.contains(&account_id),
+            RafflerError::AlreadyBanned {
+                account_id: account_id.clone(),
+            },
+        );
 
-        testing_env!(context.build());
-        let first_event_id = contract.add_event(String::from("title"), None, None);
-        let second_event_id = contract.add_event(String::from("title"), None, None);
+        let was_participant = event.participants.contains(&account_id);
+
+        if was_participant {
+            self.internal_remove_participant(&mut event, &account_id);
+        }
+
+        let storage_before = env::storage_usage();
+
+        event.banned.insert(&account_id);
+
+        self.internal_save_event(event);
+
+        self.internal_pay_for_event_storage(&event_id, env::attached_deposit(), storage_before);
+
+        RafflerLogEvent::BanParticipant(vec![BanParticipantLog {
+            event_id,
+            account_id,
+            was_participant,
+        }])
+        .emit();
+    }
+
+    #[witgen]
+    #[payable]
+    pub fn raffle_event_prizes(&mut self, event_id: EventId) -> Vec<EventPrize> {
+        self.assert_not_paused();
+
+        assert_at_least_one_yocto();
+
+        let event = self.internal_get_event(&event_id);
+
+        assert_event_permission(&event, EventPermission::Raffle);
+
+        assert_event_status(&event, EventStatus::Raffling);
+
+        let storage_before = env::storage_usage();
+
+        // the event is cancelled when it hasn't gathered enough participants,
+        // prizes couldn't be raffled without any of them even if no minimum is set
+        let prizes =
+            if event.participants.is_empty() || event.participants.len() < event.min_participants {
+                self.internal_fail_event(&event.id);
+
+                Vec::new()
+            } else {
+                self.internal_raffle_prizes(&event.id)
+            };
+
+        // records of winners are charged to the owner, who is refunded their storage on claims
+        self.internal_pay_for_event_storage(&event_id, env::attached_deposit(), storage_before);
+
+        // the next event of series is created right away, its storage is reserved by the owner
+        if let Some(series_id) = event.series_id {
+            self.internal_try_advance_series(&series_id);
+        }
+
+        prizes
+    }
+
+    #[witgen]
+    pub fn claim_prize(&mut self, prize: EventPrize) {
+        self.assert_not_paused();
+
+        self.assert_not_blocked(&env::predecessor_account_id());
+
+        // legacy event moves its unclaimed prizes to the current mapping once it's migrated
+        if self.internal_is_legacy_event(&prize.event_id) {
+            self.internal_get_event(&prize.event_id);
+        }
+
+        assert_condition(
+            self.unclaimed_prizes_by_account
+                .get(&env::predecessor_account_id())
+                .is_some_and(|prizes| prizes.contains_key(&prize)),
+            RafflerError::PrizeNotFound,
+        );
+
+        let mut event = self.internal_get_event(&prize.event_id);
+
+        assert_event_status(&event, EventStatus::Claiming);
+
+        assert_condition(
+            prize.prize_index < event.prizes.len(),
+            RafflerError::PrizeNotFound,
+        );
+
+        let mut actual_prize = Prize::from(event.prizes.get(prize.prize_index).unwrap());
+
+        assert_condition(
+            prize.slot_index < actual_prize.winners.len() as u64,
+            RafflerError::PrizeNotFound,
+        );
+
+        let storage_before = env::storage_usage();
+
+        self.internal_remove_unclaimed_prize(&env::predecessor_account_id(), &prize);
+
+        let winner = &mut actual_prize.winners[prize.slot_index as usize];
+
+        winner.claimed = true;
+        winner.claimed_at = Some(current_time_ms());
+
+        // all prizes are paid in Near, the share of slot was computed on raffle
+        let amount = winner.amount;
+
+        event
+            .prizes
+            .replace(prize.prize_index, &VersionedPrize::from(actual_prize));
+
+        self.internal_save_event(event);
+
+        // the record of unclaimed prize was paid on raffle as the storage of event
+        self.internal_release_event_storage(
+            &prize.event_id,
+            storage_before.saturating_sub(env::storage_usage()),
+        );
+
+        Promise::new(env::predecessor_account_id()).transfer(amount.0);
+
+        RafflerLogEvent::ClaimPrize(vec![ClaimPrizeLog {
+            event_id: prize.event_id,
+            prize_index: prize.prize_index,
+            slot_index: prize.slot_index,
+            account_id: env::predecessor_account_id(),
+            amount,
+        }])
+        .emit();
+    }
+
+    // should be called repeatedly until it returns true, since participants are released in batches
+    #[witgen]
+    #[payable]
+    pub fn archive_event(&mut self, event_id: EventId, limit: Option<u64>) -> bool {
+        self.assert_not_paused();
+
+        assert_exactly_one_yocto();
+
+        let event = self.internal_get_event(&event_id);
+
+        assert_event_permission(&event, EventPermission::Configure);
+
+        // failed event has nothing to settle, so it can be archived right away
+        if !event.archived && !event.failed {
+            assert_event_status(&event, EventStatus::Claiming);
+
+            let all_prizes_claimed = event.prizes.iter().map(Prize::from).all(|p| p.is_claimed());
+
+            assert_condition(
+                all_prizes_claimed || current_time_ms() >= event.ended_at + PRIZE_CLAIM_PERIOD_MS,
+                RafflerError::PrizesNotSettled { event_id },
+            );
+        }
+
+        self.internal_archive_event(event, limit.unwrap_or(DEFAULT_ARCHIVE_BATCH_SIZE))
+    }
+
+    #[witgen]
+    #[payable]
+    pub fn propose_event_owner(&mut self, event_id: EventId, new_owner_id: Option<AccountId>) {
+        self.assert_not_paused();
+
+        assert_exactly_one_yocto();
+
+        let mut event = self.internal_get_event(&event_id);
+
+        assert_event_owner(&event);
+
+        // passing nothing cancels the pending proposal
+        if let Some(new_owner_id) = &new_owner_id {
+            assert_condition(
+                &event.owner_id != new_owner_id,
+                RafflerError::AlreadyEventOwner {
+                    account_id: new_owner_id.clone(),
+                },
+            );
+
+            assert_condition(
+                !event.participants.contains(new_owner_id),
+                RafflerError::OwnerCantParticipate,
+            );
+        }
+
+        event.pending_owner_id = new_owner_id;
+
+        self.internal_save_event(event);
+    }
+
+    #[witgen]
+    #[payable]
+    pub fn accept_event_owner(&mut self, event_id: EventId) {
+        self.assert_not_paused();
+
+        assert_at_least_one_yocto();
+
+        let event = self.internal_get_event(&event_id);
+
+        assert_condition(
+            event.pending_owner_id.as_ref() == Some(&env::predecessor_account_id()),
+            RafflerError::OwnershipNotProposed,
+        );
+
+        assert_condition(
+            !event.participants.contains(&env::predecessor_account_id()),
+            RafflerError::OwnerCantParticipate,
+        );
+
+        // storage of the event is moved to the new owner, so it's paid by the attached deposit
+        self.internal_deposit_storage(&env::predecessor_account_id(), env::attached_deposit());
+
+        self.internal_transfer_event_owner(event, &env::predecessor_account_id());
+    }
+
+    #[witgen]
+    #[payable]
+    pub fn grant_event_role(&mut self, event_id: EventId, account_id: AccountId, role: EventRole) {
+        self.assert_not_paused();
+
+        assert_at_least_one_yocto();
+
+        let event = self.internal_get_event(&event_id);
+
+        assert_event_owner(&event);
+
+        assert_condition(
+            event.owner_id != account_id,
+            RafflerError::OwnerHasAllPermissions,
+        );
+
+        assert_condition(
+            !event.participants.contains(&account_id),
+            RafflerError::RoleHolderCantParticipate,
+        );
+
+        let storage_before = env::storage_usage();
+
+        self.internal_set_event_role(event, &account_id, Some(role));
+
+        self.internal_pay_for_event_storage(&event_id, env::attached_deposit(), storage_before);
+    }
+
+    #[witgen]
+    #[payable]
+    pub fn revoke_event_role(&mut self, event_id: EventId, account_id: AccountId) {
+        self.assert_not_paused();
+
+        assert_exactly_one_yocto();
+
+        let event = self.internal_get_event(&event_id);
+
+        assert_event_owner(&event);
+
+        assert_condition(
+            event.roles.get(&account_id).is_some(),
+            RafflerError::NoEventRole {
+                account_id: account_id.clone(),
+            },
+        );
+
+        let storage_before = env::storage_usage();
+
+        self.internal_set_event_role(event, &account_id, None);
+
+        // the owner has paid for the role
+        self.internal_release_event_storage(&event_id, storage_before - env::storage_usage());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::admin::types::{BlocklistEntry, Config};
+    use crate::event::types::{
+        EventMetadata, EventPrize, EventRole, EventStatus, PrizeDefinition, PrizeSplit, PrizeType,
+        PRIZE_CLAIM_PERIOD_MS,
+    };
+
+    use super::Contract;
+    use near_sdk::json_types::{Base64VecU8, U128};
+    use near_sdk::test_utils::{get_logs, VMContextBuilder};
+    use near_sdk::testing_env;
+    use near_sdk::MockedBlockchain;
+
+    const CURRENT_TIME: u64 = 1_000_000;
+    const START_TIME: u64 = 2_000_000;
+    const ACTIVE_TIME: u64 = 3_000_000;
+    const END_TIME: u64 = 4_000_000;
+    const RAFFLE_TIME: u64 = 5_000_000;
+    const CLAIM_TIME: u64 = 6_000_000;
+
+    #[test]
+    fn pass_add_two_events_with_diff_ids() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let first_event_id = contract.add_event(String::from("title"), None, None, None);
+        let second_event_id = contract.add_event(String::from("title"), None, None, None);
+
+        // event ids should be different
+        assert_eq!(first_event_id + 1, second_event_id);
+    }
+
+    #[test]
+    #[should_panic(expected = "'title' should be between 4 and 64 symbols")]
+    fn panic_on_add_event_with_long_title() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        // more than 64 symbols
+        let big_title =
+            "title_more_than_64_symbols+title_more_than_64_symbols+title_more_than_64_symbols";
+
+        testing_env!(context.build());
+        contract.add_event(String::from(big_title), None, None, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "'title' should be between 4 and 64 symbols")]
+    fn panic_on_add_event_with_short_title() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        // less than 4 symbols
+        let short_title = "s";
+
+        testing_env!(context.build());
+        contract.add_event(String::from(short_title), None, None, None);
+    }
+
+    #[test]
+    fn pass_add_event() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        contract.add_event(String::from("title"), None, None, None);
+    }
+
+    fn get_event_metadata() -> EventMetadata {
+        EventMetadata {
+            description: Some(String::from("description")),
+            media: Some(String::from("https://example.com/cover.png")),
+            media_hash: Some(Base64VecU8(vec![0; 32])),
+            link: Some(String::from("https://example.com")),
+            tags: vec![String::from("music"), String::from("art")],
+            terms: Some(String::from("terms")),
+        }
+    }
+
+    #[test]
+    fn pass_add_event_with_metadata() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            None,
+            None,
+            Some(get_event_metadata()),
+        );
+
+        let event = contract.get_event(event_id).unwrap();
+
+        assert_eq!(event.metadata, Some(get_event_metadata()));
+    }
+
+    #[test]
+    #[should_panic(expected = "'media' must be an 'https://' or 'ipfs://' URL")]
+    fn panic_on_add_event_with_invalid_media_url() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        let mut metadata = get_event_metadata();
+        metadata.media = Some(String::from("http://example.com/cover.png"));
+
+        testing_env!(context.build());
+        contract.add_event(String::from("title"), None, None, Some(metadata));
+    }
+
+    #[test]
+    #[should_panic(expected = "'media_hash' must be a sha256 of 'media'")]
+    fn panic_on_add_event_with_invalid_media_hash() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        let mut metadata = get_event_metadata();
+        metadata.media_hash = Some(Base64VecU8(vec![0; 16]));
+
+        testing_env!(context.build());
+        contract.add_event(String::from("title"), None, None, Some(metadata));
+    }
+
+    #[test]
+    #[should_panic(expected = "Event could have max 5 tags")]
+    fn panic_on_add_event_with_too_many_tags() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        let mut metadata = get_event_metadata();
+        metadata.tags = vec![String::from("tag"); 6];
+
+        testing_env!(context.build());
+        contract.add_event(String::from("title"), None, None, Some(metadata));
+    }
+
+    #[test]
+    fn pass_set_event_metadata() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(String::from("title"), None, None, None);
+
+        let available_before = contract
+            .storage_balance_of("owner".try_into().unwrap())
+            .unwrap()
+            .available;
+
+        testing_env!(context.attached_deposit(0).build());
+        contract.set_event_metadata(event_id, get_event_metadata());
+
+        assert_eq!(
+            contract.get_event(event_id).unwrap().metadata,
+            Some(get_event_metadata())
+        );
+
+        let available_with_metadata = contract
+            .storage_balance_of("owner".try_into().unwrap())
+            .unwrap()
+            .available;
+
+        // bigger metadata is charged from the storage balance
+        assert!(available_with_metadata.0 < available_before.0);
+
+        contract.set_event_metadata(event_id, EventMetadata::default());
+
+        // and released once it's reduced
+        assert_eq!(
+            contract
+                .storage_balance_of("owner".try_into().unwrap())
+                .unwrap()
+                .available,
+            available_before
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Event status isn't 'Configuration'")]
+    fn panic_on_set_event_metadata_of_visible_event() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(String::from("title"), None, None, None);
+
+        contract.add_near_prize(event_id, U128(1_000_000_000_000_000_000_000_000), None);
+        contract.set_event_visible(event_id);
+
+        contract.set_event_metadata(event_id, get_event_metadata());
+    }
+
+    #[test]
+    #[should_panic(expected = "'start_time' couldn't be set to value in the past")]
+    fn panic_on_add_event_with_start_before_now() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.block_timestamp(ACTIVE_TIME * 1_000_000).build());
+        contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "'start_time' couldn't be set to value in the past")]
+    fn panic_on_set_event_time_with_start_before_now() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.block_timestamp(ACTIVE_TIME * 1_000_000).build());
+        let event_id = contract.add_event(String::from("title"), None, None, None);
+
+        contract.set_event_time(event_id.clone(), START_TIME, END_TIME);
+    }
+
+    #[test]
+    fn pass_extend_and_end_active_event() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        contract.add_near_prize(event_id, U128(1_000_000_000_000_000_000_000_000), None);
+        contract.set_event_visible(event_id);
+
+        testing_env!(context.block_timestamp(ACTIVE_TIME * 1_000_000).build());
+        contract.extend_event(event_id, CLAIM_TIME);
+
+        let event = contract.get_event(event_id).unwrap();
+
+        assert_eq!(event.ended_at, CLAIM_TIME);
+
+        testing_env!(context.block_timestamp(RAFFLE_TIME * 1_000_000).build());
+        assert_eq!(
+            contract.get_event(event_id).unwrap().status,
+            EventStatus::Active
+        );
+
+        contract.end_event(event_id);
+
+        let event = contract.get_event(event_id).unwrap();
+
+        assert_eq!(event.status, EventStatus::Raffling);
+        assert_eq!(event.ended_at, RAFFLE_TIME);
+        assert_eq!(
+            get_logs().last().unwrap(),
+            &format!(
+                r#"EVENT_JSON:{{"standard":"raffler","version":"1.0.0","event":"end_event","data":[{{"event_id":{},"ended_at":{}}}]}}"#,
+                event_id, RAFFLE_TIME
+            )
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Event couldn't be extended by more than 1000 milliseconds in total")]
+    fn panic_on_extending_event_beyond_config() {
+        let mut context = VMContextBuilder::new();
+
+        testing_env!(context
+            .predecessor_account_id("admin".try_into().unwrap())
+            .attached_deposit(1)
+            .build());
+        let mut contract = Contract::new();
+
+        contract.set_config(Config {
+            max_event_extension: 1_000,
+            ..Config::default()
+        });
+
+        testing_env!(context
+            .attached_deposit(2_000_000_000_000_000_000_000_000)
+            .predecessor_account_id("owner".try_into().unwrap())
+            .build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        contract.add_near_prize(event_id, U128(1_000_000_000_000_000_000_000_000), None);
+        contract.set_event_visible(event_id);
+
+        testing_env!(context.block_timestamp(ACTIVE_TIME * 1_000_000).build());
+        contract.extend_event(event_id, END_TIME + 600);
+        contract.extend_event(event_id, END_TIME + 1_200);
+    }
+
+    #[test]
+    #[should_panic(expected = "Event status isn't 'Active'")]
+    fn panic_on_ending_not_started_event() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        contract.add_near_prize(event_id, U128(1_000_000_000_000_000_000_000_000), None);
+        contract.set_event_visible(event_id);
+
+        contract.end_event(event_id);
+    }
+
+    #[test]
+    #[should_panic(expected = "'end_time' must be bigger than 'start_time'")]
+    fn panic_on_add_event_with_end_before_start() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        contract.add_event(
+            String::from("title"),
+            Some(END_TIME),
+            Some(START_TIME),
+            None,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "'end_time' must be bigger than 'start_time'")]
+    fn panic_on_set_event_time_with_end_before_start() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(String::from("title"), None, None, None);
+
+        testing_env!(context.build());
+        contract.set_event_time(event_id.clone(), END_TIME, START_TIME);
+    }
+
+    #[test]
+    #[should_panic(expected = "Both 'start_time' and 'end_time' either must be present or absent")]
+    fn panic_on_add_event_with_only_start_time() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        contract.add_event(String::from("title"), Some(START_TIME), None, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Both 'start_time' and 'end_time' either must be present or absent")]
+    fn panic_on_add_event_with_only_end_time() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        contract.add_event(String::from("title"), None, Some(END_TIME), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Couldn't set event visible since there're no prizes")]
+    fn panic_on_set_event_visible_without_prize() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.set_event_visible(event_id.clone());
+    }
+
+    #[test]
+    #[should_panic(expected = "Event status isn't 'Configuration'")]
+    fn panic_on_set_event_visible_twice() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.set_event_visible(event_id.clone());
+        contract.set_event_visible(event_id.clone());
+    }
+
+    #[test]
+    #[should_panic(expected = "This method can be accessed only by the owner")]
+    fn panic_on_no_owner_accessing_event_time() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        testing_env!(context
+            .predecessor_account_id("den".try_into().unwrap())
+            .build());
+        contract.set_event_time(event_id.clone(), START_TIME, END_TIME);
+    }
+
+    #[test]
+    #[should_panic(expected = "This method can be accessed only by the owner")]
+    fn panic_on_no_owner_accessing_event_visibity() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
+
+        testing_env!(context
+            .predecessor_account_id("den".try_into().unwrap())
+            .build());
+        contract.set_event_visible(event_id.clone());
+    }
+
+    #[test]
+    #[should_panic(expected = "This method can be accessed only by the owner")]
+    fn panic_on_no_owner_accessing_event_prizes() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        testing_env!(context
+            .predecessor_account_id("den".try_into().unwrap())
+            .build());
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "This method can be accessed only by the owner")]
+    fn panic_on_no_owner_accessing_raffle_event_prizes() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.set_event_visible(event_id.clone());
+
+        testing_env!(context
+            .predecessor_account_id("den".try_into().unwrap())
+            .block_timestamp(RAFFLE_TIME * 1_000_000)
+            .build());
+        contract.raffle_event_prizes(event_id.clone());
+    }
+
+    #[test]
+    fn pass_owner_set_event_visible() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.set_event_visible(event_id.clone());
+    }
+
+    #[test]
+    #[should_panic(expected = "Event status isn't 'Configuration'")]
+    fn panic_on_set_event_time_after_event_visible() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        context.block_timestamp(CURRENT_TIME * 1_000_000);
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(String::from("title"), None, None, None);
+
+        println!("event_id: {}", event_id);
+        let event = contract.get_event(event_id.clone()).unwrap();
+        println!("event_before_near_prize: {:?}", event);
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
+        let event = contract.get_event(event_id.clone()).unwrap();
+        println!("event_after_near_prize: {:?}", event);
+        contract.set_event_visible(event_id.clone());
+
+        let event = contract.get_event(event_id.clone()).unwrap();
+        println!("event_after_event_visible: {:?}", event);
+        contract.set_event_time(event_id.clone(), START_TIME, END_TIME);
+        let event = contract.get_event(event_id.clone()).unwrap();
+        println!("event_after_event_time: {:?}", event);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Prize amount couldn't be less than 100000000000000000000000 yoctoNear"
+    )]
+    fn panic_on_adding_small_near_prize_for_event() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.add_near_prize(event_id.clone(), U128(1_000_000), None);
+    }
+
+    #[test]
+    fn pass_adding_event_near_prize() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(100_000_000_000_000_000_000_000),
+            None,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Event status isn't 'Configuration'")]
+    fn panic_on_adding_near_prize_for_visible_event() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
+        testing_env!(context.build());
+        contract.set_event_visible(event_id.clone());
+
+        testing_env!(context.block_timestamp(CURRENT_TIME * 1_000_000).build());
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
+    }
+
+    #[test]
+    fn pass_create_event_full() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(5_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.create_event_full(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            Some(get_event_metadata()),
+            vec![
+                PrizeDefinition {
+                    prize_type: PrizeType::NEAR {
+                        amount: U128(1_000_000_000_000_000_000_000_000),
+                    },
+                    quantity: None,
+                },
+                PrizeDefinition {
+                    prize_type: PrizeType::NEAR {
+                        amount: U128(500_000_000_000_000_000_000_000),
+                    },
+                    quantity: Some(3),
+                },
+            ],
+            true,
+        );
+
+        let event = contract.get_event(event_id).unwrap();
+
+        assert_eq!(event.status, EventStatus::Visible);
+        assert_eq!(event.prizes.len(), 2);
+        assert_eq!(event.prizes[0].quantity, 1);
+        assert_eq!(event.prizes[1].quantity, 3);
+
+        // only the measured storage is paid, the excess is refunded
+        let balance = contract
+            .storage_balance_of("owner".try_into().unwrap())
+            .unwrap();
+
+        assert_eq!(balance.available, U128(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "You should attach at least")]
+    fn panic_on_create_event_full_without_storage_deposit() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        // covers only the prize
+        context.attached_deposit(1_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        contract.create_event_full(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+            vec![PrizeDefinition {
+                prize_type: PrizeType::NEAR {
+                    amount: U128(1_000_000_000_000_000_000_000_000),
+                },
+                quantity: None,
+            }],
+            false,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Couldn't set event visible since there're no prizes")]
+    fn panic_on_publish_event_full_without_prizes() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        contract.create_event_full(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+            vec![],
+            true,
+        );
+    }
+
+    #[test]
+    fn pass_clone_event() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(5_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let source_event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            Some(get_event_metadata()),
+        );
+
+        contract.set_event_ticket_price(source_event_id, U128(1_000));
+        contract.add_near_prize(
+            source_event_id,
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
+        contract.add_near_prize(source_event_id, U128(500_000_000_000_000_000_000_000), None);
+        contract.set_event_visible(source_event_id);
+
+        testing_env!(context.block_timestamp(ACTIVE_TIME * 1_000_000).build());
+        let event_id = contract.clone_event(source_event_id, RAFFLE_TIME, CLAIM_TIME);
+
+        let event = contract.get_event(event_id).unwrap();
+
+        assert_ne!(event_id, source_event_id);
+        assert_eq!(event.status, EventStatus::Configuration);
+        assert_eq!(event.title, String::from("title"));
+        assert_eq!(event.metadata, Some(get_event_metadata()));
+        assert_eq!(event.ticket_price, U128(1_000));
+        assert_eq!(event.started_at, RAFFLE_TIME);
+        assert_eq!(event.ended_at, CLAIM_TIME);
+        assert_eq!(event.prizes.len(), 2);
+        assert!(event.prizes.iter().all(|p| p.winners.is_empty()));
+    }
+
+    #[test]
+    #[should_panic(expected = "This method can be accessed only by the owner")]
+    fn panic_on_cloning_event_by_not_owner() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let source_event_id = contract.add_event(String::from("title"), None, None, None);
+
+        testing_env!(context
+            .predecessor_account_id("participant".try_into().unwrap())
+            .build());
+        contract.clone_event(source_event_id, START_TIME, END_TIME);
+    }
+
+    #[test]
+    #[should_panic(expected = "You should attach at least 1000000000000000000000000 yoctoNear")]
+    fn panic_on_cloning_event_without_prizes_deposit() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let source_event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        contract.add_near_prize(
+            source_event_id,
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.clone_event(source_event_id, START_TIME, END_TIME);
+    }
+
+    #[test]
+    #[should_panic(expected = "Event status isn't 'Active'")]
+    fn panic_on_joining_event_before_start() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.set_event_visible(event_id.clone());
+
+        testing_env!(context
+            .predecessor_account_id("den".try_into().unwrap())
+            .block_timestamp((START_TIME - 1_000_000) * 1_000_000)
+            .build());
+        contract.join_event(event_id.clone());
+    }
+
+    #[test]
+    #[should_panic(expected = "Event status isn't 'Active'")]
+    fn panic_on_joining_event_after_end() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.set_event_visible(event_id.clone());
+
+        testing_env!(context
+            .predecessor_account_id("den".try_into().unwrap())
+            .block_timestamp((END_TIME + 1_000_000) * 1_000_000)
+            .build());
+        contract.join_event(event_id.clone());
+    }
+
+    #[test]
+    #[should_panic(expected = "You're already participating in this event")]
+    fn panic_on_multiple_joining_event() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.set_event_visible(event_id.clone());
+
+        testing_env!(context
+            .block_timestamp(ACTIVE_TIME * 1_000_000)
+            .predecessor_account_id("den".try_into().unwrap())
+            .build());
+        contract.join_event(event_id.clone());
+        contract.join_event(event_id.clone());
+    }
+
+    #[test]
+    #[should_panic(expected = "Owner can't participate his own events")]
+    fn panic_on_joining_owned_event() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.set_event_visible(event_id.clone());
+
+        context.block_timestamp(ACTIVE_TIME * 1_000_000);
+
+        testing_env!(context.build());
+        contract.join_event(event_id.clone());
+    }
+
+    #[test]
+    fn pass_join_event() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.set_event_visible(event_id.clone());
+
+        context.block_timestamp(ACTIVE_TIME * 1_000_000);
+
+        testing_env!(context
+            .predecessor_account_id("den".try_into().unwrap())
+            .build());
+        contract.join_event(event_id.clone());
+    }
+
+    #[test]
+    fn pass_raffle_event_prizes_for_multiple_participants() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.set_event_visible(event_id.clone());
+
+        context.block_timestamp(ACTIVE_TIME * 1_000_000);
+
+        testing_env!(context
+            .predecessor_account_id("den".try_into().unwrap())
+            .build());
+        contract.join_event(event_id.clone());
+
+        testing_env!(context
+            .predecessor_account_id("den2".try_into().unwrap())
+            .build());
+        contract.join_event(event_id.clone());
+        testing_env!(context
+            .predecessor_account_id("den3".try_into().unwrap())
+            .build());
+        contract.join_event(event_id.clone());
+
+        testing_env!(context
+            .predecessor_account_id("owner".try_into().unwrap())
+            .block_timestamp(RAFFLE_TIME * 1_000_000)
+            .build());
+        let prizes = contract.raffle_event_prizes(event_id.clone());
+
+        assert!(prizes.len() == 1);
+    }
+    #[test]
+    fn pass_raffle_and_claim_prize_with_quantity() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        contract.add_near_prize(event_id, U128(500_000_000_000_000_000_000_000), Some(3));
+        contract.set_event_visible(event_id);
+
+        context.block_timestamp(ACTIVE_TIME * 1_000_000);
+
+        testing_env!(context
+            .predecessor_account_id("den".try_into().unwrap())
+            .build());
+        contract.join_event(event_id);
+
+        testing_env!(context
+            .predecessor_account_id("den2".try_into().unwrap())
+            .build());
+        contract.join_event(event_id);
+
+        testing_env!(context
+            .predecessor_account_id("owner".try_into().unwrap())
+            .block_timestamp(RAFFLE_TIME * 1_000_000)
+            .build());
+        let prizes = contract.raffle_event_prizes(event_id);
+
+        // there are only 2 participants, so the last slot is refunded to the owner
+        assert_eq!(prizes.len(), 2);
+        assert_eq!(prizes[1].slot_index, 1);
+
+        let event = contract.get_event(event_id).unwrap();
+
+        assert_eq!(event.prizes[0].quantity, 3);
+        assert_eq!(event.prizes[0].winners.len(), 2);
+        assert_ne!(
+            event.prizes[0].winners[0].account_id,
+            event.prizes[0].winners[1].account_id
+        );
+
+        let winner_id = event.prizes[0].winners[1].account_id.clone();
+
+        testing_env!(context
+            .predecessor_account_id(winner_id.clone())
+            .block_timestamp(CLAIM_TIME * 1_000_000)
+            .build());
+        contract.claim_prize(prizes[1].clone());
+
+        let winners = contract.get_event_winners(event_id);
+
+        assert_eq!(winners.len(), 2);
+        assert_eq!(winners[0].claimed, false);
+        assert_eq!(winners[1].slot_index, 1);
+        assert_eq!(winners[1].winner_account_id, winner_id);
+        assert_eq!(winners[1].claimed, true);
+    }
+
+    #[test]
+    fn pass_raffle_max_winners_of_event() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(10_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        contract.add_near_prize(event_id, U128(100_000_000_000_000_000_000_000), Some(60));
+        contract.add_split_pot_prize(
+            event_id,
+            U128(1_000_000_000_000_000_000_000_000),
+            PrizeSplit::Equal { winners: 40 },
+        );
+        contract.set_event_visible(event_id);
+
+        context.block_timestamp(ACTIVE_TIME * 1_000_000);
+
+        for index in 0..100 {
+            testing_env!(context
+                .predecessor_account_id(format!("participant{}", index).try_into().unwrap())
+                .attached_deposit(100_000_000_000_000_000_000_000)
+                .build());
+            contract.join_event(event_id);
+        }
+
+        testing_env!(context
+            .predecessor_account_id("owner".try_into().unwrap())
+            .block_timestamp(RAFFLE_TIME * 1_000_000)
+            .build());
+        let prizes = contract.raffle_event_prizes(event_id);
+
+        assert_eq!(prizes.len(), 100);
+    }
+
+    #[test]
+    #[should_panic(expected = "Event could have max 100 winners across all prizes")]
+    fn panic_on_exceeding_max_winners_of_event() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(10_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        contract.add_near_prize(event_id, U128(100_000_000_000_000_000_000_000), Some(60));
+        contract.add_split_pot_prize(
+            event_id,
+            U128(1_000_000_000_000_000_000_000_000),
+            PrizeSplit::Equal { winners: 40 },
+        );
+        contract.add_near_prize(event_id, U128(100_000_000_000_000_000_000_000), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Prize quantity should be between 1 and 100")]
+    fn panic_on_adding_near_prize_with_zero_quantity() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        contract.add_near_prize(event_id, U128(1_000_000_000_000_000_000_000_000), Some(0));
+    }
+
+    #[test]
+    fn pass_raffle_and_claim_split_pot_prize() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        contract.add_split_pot_prize(
+            event_id,
+            U128(1_000_000_000_000_000_000_000_000),
+            PrizeSplit::Percentage {
+                shares_bps: vec![5_000, 3_000, 2_000],
+            },
+        );
+        contract.add_split_pot_prize(
+            event_id,
+            U128(1_000_000_000_000_000_000_000_000),
+            PrizeSplit::Equal { winners: 3 },
+        );
+        contract.set_event_visible(event_id);
+
+        context.block_timestamp(ACTIVE_TIME * 1_000_000);
+
+        for participant in ["den", "den2", "den3"] {
+            testing_env!(context
+                .predecessor_account_id(participant.try_into().unwrap())
+                .build());
+            contract.join_event(event_id);
+        }
+
+        testing_env!(context
+            .predecessor_account_id("owner".try_into().unwrap())
+            .block_timestamp(RAFFLE_TIME * 1_000_000)
+            .build());
+        let prizes = contract.raffle_event_prizes(event_id);
+
+        assert_eq!(prizes.len(), 6);
+
+        let winners = contract.get_event_winners(event_id);
+
+        assert_eq!(winners[0].amount, U128(500_000_000_000_000_000_000_000));
+        assert_eq!(winners[1].amount, U128(300_000_000_000_000_000_000_000));
+        assert_eq!(winners[2].amount, U128(200_000_000_000_000_000_000_000));
+        // rounding dust of equal shares is refunded to the owner on raffle
+        assert!(winners[3..]
+            .iter()
+            .all(|winner| winner.amount == U128(333_333_333_333_333_333_333_333)));
+
+        testing_env!(context
+            .predecessor_account_id(winners[1].winner_account_id.clone())
+            .block_timestamp(CLAIM_TIME * 1_000_000)
+            .build());
+        contract.claim_prize(prizes[1].clone());
+
+        let winners = contract.get_event_winners(event_id);
+
+        assert_eq!(winners[0].claimed, false);
+        assert_eq!(winners[1].claimed, true);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Prize should be split among 1 to 100 winners, percentages should be positive and sum up to 100%"
+    )]
+    fn panic_on_adding_split_pot_prize_with_invalid_percentages() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        contract.add_split_pot_prize(
+            event_id,
+            U128(1_000_000_000_000_000_000_000_000),
+            PrizeSplit::Percentage {
+                shares_bps: vec![5_000, 3_000],
+            },
+        );
+    }
+
+    #[test]
+    fn pass_raffle_event_prizes() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.set_event_visible(event_id.clone());
+
+        context.block_timestamp(ACTIVE_TIME * 1_000_000);
+
+        testing_env!(context
+            .predecessor_account_id("den".try_into().unwrap())
+            .build());
+        contract.join_event(event_id.clone());
+
+        testing_env!(context
+            .predecessor_account_id("owner".try_into().unwrap())
+            .block_timestamp(RAFFLE_TIME * 1_000_000)
+            .build());
+        let prizes = contract.raffle_event_prizes(event_id.clone());
+
+        assert!(prizes.len() == 1);
+
+        let json_event = contract.get_event(event_id).unwrap();
+
+        let internal_prize = json_event.prizes.get(0).unwrap();
+
+        assert_eq!(internal_prize.winners[0].claimed, false);
+        assert_eq!(internal_prize.winners[0].account_id, "den".to_string());
+    }
+
+    #[test]
+    fn pass_fail_event_without_participants() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.set_event_visible(event_id.clone());
+
+        testing_env!(context
+            .predecessor_account_id("owner".try_into().unwrap())
+            .block_timestamp(RAFFLE_TIME * 1_000_000)
+            .build());
+        let prizes = contract.raffle_event_prizes(event_id.clone());
+
+        // prizes are refunded to the owner instead of the raffle
+        assert!(prizes.is_empty());
+
+        let event = contract.get_event(event_id).unwrap();
+
+        assert_eq!(event.status, EventStatus::Failed);
+        assert_eq!(event.min_participants, 0);
+    }
+
+    #[test]
+    fn pass_fail_event_below_min_participants() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        contract.set_event_min_participants(event_id, 2);
+        contract.set_event_ticket_price(event_id, U128(1_000));
+        contract.add_near_prize(event_id, U128(1_000_000_000_000_000_000_000_000), None);
+        contract.set_event_visible(event_id);
+
+        testing_env!(context
+            .predecessor_account_id("den".try_into().unwrap())
+            .block_timestamp(ACTIVE_TIME * 1_000_000)
+            .build());
+        contract.join_event(event_id);
+
+        testing_env!(context
+            .predecessor_account_id("owner".try_into().unwrap())
+            .block_timestamp(RAFFLE_TIME * 1_000_000)
+            .build());
+        let prizes = contract.raffle_event_prizes(event_id);
+
+        assert!(prizes.is_empty());
+
+        let event = contract.get_event(event_id).unwrap();
+
+        assert_eq!(event.status, EventStatus::Failed);
+        assert_eq!(event.min_participants, 2);
+        assert!(event.prizes[0].winners.is_empty());
+        assert!(contract
+            .get_account_unclaimed_prizes("den".to_string(), None)
+            .items
+            .is_empty());
+
+        // nothing to settle, so the event is archived without waiting for claims
+        testing_env!(context.attached_deposit(1).build());
+        assert!(contract.archive_event(event_id, None));
+    }
+
+    #[test]
+    #[should_panic(expected = "'min_participants' should be at most 100")]
+    fn panic_on_setting_too_high_min_participants() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        contract.set_event_min_participants(event_id, 101);
+    }
+
+    #[test]
+    fn pass_claim_event_prize() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.set_event_visible(event_id.clone());
+
+        context.block_timestamp(ACTIVE_TIME * 1_000_000);
+
+        testing_env!(context
+            .predecessor_account_id("den".try_into().unwrap())
+            .build());
+        contract.join_event(event_id.clone());
+
+        testing_env!(context
+            .predecessor_account_id("owner".try_into().unwrap())
+            .block_timestamp(RAFFLE_TIME * 1_000_000)
+            .build());
+        let prizes = contract.raffle_event_prizes(event_id.clone());
+
+        let den_prize = prizes.get(0).unwrap().clone();
+
+        testing_env!(context
+            .predecessor_account_id("den".try_into().unwrap())
+            .block_timestamp(CLAIM_TIME * 1_000_000)
+            .build());
+
+        let unclaimed = contract.get_account_unclaimed_prizes("den".to_string(), None);
+        assert_eq!(unclaimed.items.len(), 1);
+
+        let available_before = contract
+            .storage_balance_of("owner".try_into().unwrap())
+            .unwrap()
+            .available;
+
+        contract.claim_prize(den_prize.clone());
+
+        let unclaimed = contract.get_account_unclaimed_prizes("den".to_string(), None);
+        assert_eq!(unclaimed.items.len(), 0);
+
+        // the record of unclaimed prize is released to the owner
+        assert!(
+            contract
+                .storage_balance_of("owner".try_into().unwrap())
+                .unwrap()
+                .available
+                .0
+                > available_before.0
+        );
+
+        let json_event = contract.get_event(event_id).unwrap();
+
+        let internal_prize = json_event
+            .prizes
+            .get(den_prize.prize_index as usize)
+            .unwrap();
 
-        // event ids should be different
-        assert_eq!(first_event_id + 1, second_event_id);
+        assert_eq!(internal_prize.winners[0].claimed, true);
+        assert_eq!(internal_prize.winners[0].account_id, "den".to_string());
     }
 
     #[test]
-    #[should_panic(expected = "'title' should be between 4 and 64 symbols")]
-    fn panic_on_add_event_with_long_title() {
-        let mut contract = Contract::new();
-
+    #[should_panic(expected = "Account is blocked (account den)")]
+    fn panic_on_claiming_prize_by_blocked_winner() {
         let mut context = VMContextBuilder::new();
 
-        context.attached_deposit(2_000_000_000_000_000_000_000_000);
-        context.predecessor_account_id("owner".try_into().unwrap());
+        testing_env!(context
+            .predecessor_account_id("owner".try_into().unwrap())
+            .attached_deposit(2_000_000_000_000_000_000_000_000)
+            .build());
+        let mut contract = Contract::new();
 
-        // more than 64 symbols
-        let big_title =
-            "title_more_than_64_symbols+title_more_than_64_symbols+title_more_than_64_symbols";
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
 
-        testing_env!(context.build());
-        contract.add_event(String::from(big_title), None, None);
-    }
+        contract.add_near_prize(event_id, U128(1_000_000_000_000_000_000_000_000), None);
+        contract.set_event_visible(event_id);
 
-    #[test]
-    #[should_panic(expected = "'title' should be between 4 and 64 symbols")]
-    fn panic_on_add_event_with_short_title() {
-        let mut contract = Contract::new();
+        testing_env!(context
+            .predecessor_account_id("den".try_into().unwrap())
+            .block_timestamp(ACTIVE_TIME * 1_000_000)
+            .build());
+        contract.join_event(event_id);
 
-        let mut context = VMContextBuilder::new();
+        testing_env!(context
+            .predecessor_account_id("owner".try_into().unwrap())
+            .block_timestamp(RAFFLE_TIME * 1_000_000)
+            .build());
+        let prizes = contract.raffle_event_prizes(event_id);
 
-        context.attached_deposit(2_000_000_000_000_000_000_000_000);
-        context.predecessor_account_id("owner".try_into().unwrap());
+        // owner is the contract admin as well, since the contract was initialized by it
+        testing_env!(context.attached_deposit(1).build());
+        contract.add_to_blocklist(vec![BlocklistEntry::Account {
+            account_id: "den".to_string(),
+        }]);
 
-        // less than 4 symbols
-        let short_title = "s";
+        // prize isn't lost, but flagged
+        let winners = contract.get_event_winners(event_id);
 
-        testing_env!(context.build());
-        contract.add_event(String::from(short_title), None, None);
+        assert!(winners[0].blocked);
+
+        testing_env!(context
+            .predecessor_account_id("den".try_into().unwrap())
+            .block_timestamp(CLAIM_TIME * 1_000_000)
+            .build());
+        contract.claim_prize(prizes[0].clone());
     }
 
     #[test]
-    fn pass_add_event() {
+    #[should_panic(expected = "Couldn't claim prize since it doesn't exist")]
+    fn panic_on_claiming_another_event_prize() {
         let mut contract = Contract::new();
 
         let mut context = VMContextBuilder::new();
@@ -370,42 +2594,49 @@ mod tests {
         context.predecessor_account_id("owner".try_into().unwrap());
 
         testing_env!(context.build());
-        contract.add_event(String::from("title"), None, None);
-    }
-
-    #[test]
-    #[should_panic(expected = "'start_time' couldn't be set to value in the past")]
-    fn panic_on_add_event_with_start_before_now() {
-        let mut contract = Contract::new();
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
 
-        let mut context = VMContextBuilder::new();
+        testing_env!(context.build());
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
 
-        context.attached_deposit(2_000_000_000_000_000_000_000_000);
-        context.predecessor_account_id("owner".try_into().unwrap());
+        testing_env!(context.build());
+        contract.set_event_visible(event_id.clone());
 
-        testing_env!(context.block_timestamp(ACTIVE_TIME * 1_000_000).build());
-        contract.add_event(String::from("title"), Some(START_TIME), Some(END_TIME));
-    }
+        context.block_timestamp(ACTIVE_TIME * 1_000_000);
 
-    #[test]
-    #[should_panic(expected = "'start_time' couldn't be set to value in the past")]
-    fn panic_on_set_event_time_with_start_before_now() {
-        let mut contract = Contract::new();
+        testing_env!(context
+            .predecessor_account_id("den".try_into().unwrap())
+            .build());
+        contract.join_event(event_id.clone());
 
-        let mut context = VMContextBuilder::new();
+        testing_env!(context
+            .predecessor_account_id("owner".try_into().unwrap())
+            .block_timestamp(RAFFLE_TIME * 1_000_000)
+            .build());
+        let prizes = contract.raffle_event_prizes(event_id.clone());
 
-        context.attached_deposit(2_000_000_000_000_000_000_000_000);
-        context.predecessor_account_id("owner".try_into().unwrap());
+        let den_prize = prizes.get(0).unwrap().clone();
 
-        testing_env!(context.block_timestamp(ACTIVE_TIME * 1_000_000).build());
-        let event_id = contract.add_event(String::from("title"), None, None);
+        testing_env!(context
+            .predecessor_account_id("another_den".try_into().unwrap())
+            .block_timestamp(CLAIM_TIME * 1_000_000)
+            .build());
 
-        contract.set_event_time(event_id.clone(), START_TIME, END_TIME);
+        contract.claim_prize(den_prize);
     }
 
     #[test]
-    #[should_panic(expected = "'end_time' must be bigger than 'start_time'")]
-    fn panic_on_add_event_with_end_before_start() {
+    #[should_panic(expected = "Couldn't claim prize since it doesn't exist")]
+    fn panic_on_claiming_non_existed_event_prize() {
         let mut contract = Contract::new();
 
         let mut context = VMContextBuilder::new();
@@ -414,29 +2645,52 @@ mod tests {
         context.predecessor_account_id("owner".try_into().unwrap());
 
         testing_env!(context.build());
-        contract.add_event(String::from("title"), Some(END_TIME), Some(START_TIME));
-    }
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
 
-    #[test]
-    #[should_panic(expected = "'end_time' must be bigger than 'start_time'")]
-    fn panic_on_set_event_time_with_end_before_start() {
-        let mut contract = Contract::new();
+        testing_env!(context.build());
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
 
-        let mut context = VMContextBuilder::new();
+        testing_env!(context.build());
+        contract.set_event_visible(event_id.clone());
 
-        context.attached_deposit(2_000_000_000_000_000_000_000_000);
-        context.predecessor_account_id("owner".try_into().unwrap());
+        context.block_timestamp(ACTIVE_TIME * 1_000_000);
 
-        testing_env!(context.build());
-        let event_id = contract.add_event(String::from("title"), None, None);
+        testing_env!(context
+            .predecessor_account_id("den".try_into().unwrap())
+            .build());
+        contract.join_event(event_id.clone());
 
-        testing_env!(context.build());
-        contract.set_event_time(event_id.clone(), END_TIME, START_TIME);
+        testing_env!(context
+            .predecessor_account_id("owner".try_into().unwrap())
+            .block_timestamp(RAFFLE_TIME * 1_000_000)
+            .build());
+        contract.raffle_event_prizes(event_id.clone());
+
+        let non_existed_prize = EventPrize {
+            event_id: 14241241421,
+            prize_index: 4214124,
+            slot_index: 0,
+        };
+
+        testing_env!(context
+            .predecessor_account_id("den".try_into().unwrap())
+            .block_timestamp(CLAIM_TIME * 1_000_000)
+            .build());
+
+        contract.claim_prize(non_existed_prize);
     }
 
     #[test]
-    #[should_panic(expected = "Both 'start_time' and 'end_time' either must be present or absent")]
-    fn panic_on_add_event_with_only_start_time() {
+    fn pass_transfer_event_owner() {
         let mut contract = Contract::new();
 
         let mut context = VMContextBuilder::new();
@@ -445,26 +2699,63 @@ mod tests {
         context.predecessor_account_id("owner".try_into().unwrap());
 
         testing_env!(context.build());
-        contract.add_event(String::from("title"), Some(START_TIME), None);
-    }
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
 
-    #[test]
-    #[should_panic(expected = "Both 'start_time' and 'end_time' either must be present or absent")]
-    fn panic_on_add_event_with_only_end_time() {
-        let mut contract = Contract::new();
+        testing_env!(context.attached_deposit(1).build());
+        contract.propose_event_owner(event_id.clone(), Some("den".to_string()));
 
-        let mut context = VMContextBuilder::new();
+        let json_event = contract.get_event(event_id.clone()).unwrap();
+        assert_eq!(json_event.owner_id, "owner".to_string());
+        assert_eq!(json_event.pending_owner_id, Some("den".to_string()));
 
-        context.attached_deposit(2_000_000_000_000_000_000_000_000);
-        context.predecessor_account_id("owner".try_into().unwrap());
+        let available_before = contract
+            .storage_balance_of("owner".try_into().unwrap())
+            .unwrap()
+            .available;
 
-        testing_env!(context.build());
-        contract.add_event(String::from("title"), None, Some(END_TIME));
+        testing_env!(context
+            .predecessor_account_id("den".try_into().unwrap())
+            .attached_deposit(1_000_000_000_000_000_000_000_000)
+            .build());
+        contract.accept_event_owner(event_id.clone());
+
+        let json_event = contract.get_event(event_id.clone()).unwrap();
+        assert_eq!(json_event.owner_id, "den".to_string());
+        assert_eq!(json_event.pending_owner_id, None);
+
+        let events = contract.get_owner_events("owner".to_string(), None);
+        assert_eq!(events.items.len(), 0);
+
+        let events = contract.get_owner_events("den".to_string(), None);
+        assert_eq!(events.items.len(), 1);
+
+        // storage of the event is moved from the previous owner to the new one
+        assert!(
+            contract
+                .storage_balance_of("owner".try_into().unwrap())
+                .unwrap()
+                .available
+                .0
+                > available_before.0
+        );
+        assert!(
+            contract
+                .storage_balance_of("den".try_into().unwrap())
+                .unwrap()
+                .available
+                .0
+                < 1_000_000_000_000_000_000_000_000 - contract.storage_balance_bounds().min.0
+        );
     }
 
     #[test]
-    #[should_panic(expected = "Couldn't set event visible since there're no prizes")]
-    fn panic_on_set_event_visible_without_prize() {
+    #[should_panic(expected = "Ownership of this event wasn't proposed to you")]
+    fn panic_on_accepting_not_proposed_event_owner() {
         let mut contract = Contract::new();
 
         let mut context = VMContextBuilder::new();
@@ -473,15 +2764,25 @@ mod tests {
         context.predecessor_account_id("owner".try_into().unwrap());
 
         testing_env!(context.build());
-        let event_id = contract.add_event(String::from("title"), Some(START_TIME), Some(END_TIME));
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
 
-        testing_env!(context.build());
-        contract.set_event_visible(event_id.clone());
+        testing_env!(context.attached_deposit(1).build());
+        contract.propose_event_owner(event_id.clone(), Some("den".to_string()));
+
+        testing_env!(context
+            .predecessor_account_id("den2".try_into().unwrap())
+            .build());
+        contract.accept_event_owner(event_id.clone());
     }
 
     #[test]
-    #[should_panic(expected = "Event status isn't 'Configuration'")]
-    fn panic_on_set_event_visible_twice() {
+    #[should_panic(expected = "Owner can't participate his own events")]
+    fn panic_on_proposing_participant_as_event_owner() {
         let mut contract = Contract::new();
 
         let mut context = VMContextBuilder::new();
@@ -490,19 +2791,39 @@ mod tests {
         context.predecessor_account_id("owner".try_into().unwrap());
 
         testing_env!(context.build());
-        let event_id = contract.add_event(String::from("title"), Some(START_TIME), Some(END_TIME));
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
 
         testing_env!(context.build());
-        contract.add_near_prize(event_id.clone(), U128(1_000_000_000_000_000_000_000_000));
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
 
         testing_env!(context.build());
         contract.set_event_visible(event_id.clone());
-        contract.set_event_visible(event_id.clone());
+
+        testing_env!(context
+            .block_timestamp(ACTIVE_TIME * 1_000_000)
+            .predecessor_account_id("den".try_into().unwrap())
+            .build());
+        contract.join_event(event_id.clone());
+
+        testing_env!(context
+            .attached_deposit(1)
+            .predecessor_account_id("owner".try_into().unwrap())
+            .build());
+        contract.propose_event_owner(event_id.clone(), Some("den".to_string()));
     }
 
     #[test]
-    #[should_panic(expected = "This method can be accessed only by the owner")]
-    fn panic_on_no_owner_accessing_event_time() {
+    #[should_panic(expected = "Owner can't participate his own events")]
+    fn panic_on_joining_event_by_pending_owner() {
         let mut contract = Contract::new();
 
         let mut context = VMContextBuilder::new();
@@ -511,17 +2832,35 @@ mod tests {
         context.predecessor_account_id("owner".try_into().unwrap());
 
         testing_env!(context.build());
-        let event_id = contract.add_event(String::from("title"), Some(START_TIME), Some(END_TIME));
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.set_event_visible(event_id.clone());
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.propose_event_owner(event_id.clone(), Some("den".to_string()));
 
         testing_env!(context
+            .block_timestamp(ACTIVE_TIME * 1_000_000)
             .predecessor_account_id("den".try_into().unwrap())
             .build());
-        contract.set_event_time(event_id.clone(), START_TIME, END_TIME);
+        contract.join_event(event_id.clone());
     }
 
     #[test]
-    #[should_panic(expected = "This method can be accessed only by the owner")]
-    fn panic_on_no_owner_accessing_event_visibity() {
+    fn pass_admin_configuring_and_raffling_event() {
         let mut contract = Contract::new();
 
         let mut context = VMContextBuilder::new();
@@ -530,39 +2869,54 @@ mod tests {
         context.predecessor_account_id("owner".try_into().unwrap());
 
         testing_env!(context.build());
-        let event_id = contract.add_event(String::from("title"), Some(START_TIME), Some(END_TIME));
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
 
         testing_env!(context.build());
-        contract.add_near_prize(event_id.clone(), U128(1_000_000_000_000_000_000_000_000));
+        contract.grant_event_role(event_id.clone(), "admin".to_string(), EventRole::Admin);
 
         testing_env!(context
-            .predecessor_account_id("den".try_into().unwrap())
+            .predecessor_account_id("admin".try_into().unwrap())
             .build());
-        contract.set_event_visible(event_id.clone());
-    }
-
-    #[test]
-    #[should_panic(expected = "This method can be accessed only by the owner")]
-    fn panic_on_no_owner_accessing_event_prizes() {
-        let mut contract = Contract::new();
-
-        let mut context = VMContextBuilder::new();
+        contract.set_event_time(event_id.clone(), START_TIME, END_TIME);
 
-        context.attached_deposit(2_000_000_000_000_000_000_000_000);
-        context.predecessor_account_id("owner".try_into().unwrap());
+        testing_env!(context.build());
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
 
         testing_env!(context.build());
-        let event_id = contract.add_event(String::from("title"), Some(START_TIME), Some(END_TIME));
+        contract.set_event_visible(event_id.clone());
 
         testing_env!(context
+            .block_timestamp(ACTIVE_TIME * 1_000_000)
             .predecessor_account_id("den".try_into().unwrap())
             .build());
-        contract.add_near_prize(event_id.clone(), U128(1_000_000_000_000_000_000_000_000));
+        contract.join_event(event_id.clone());
+
+        testing_env!(context
+            .predecessor_account_id("admin".try_into().unwrap())
+            .block_timestamp(RAFFLE_TIME * 1_000_000)
+            .build());
+        let prizes = contract.raffle_event_prizes(event_id.clone());
+
+        assert!(prizes.len() == 1);
+
+        // storage of the event is charged to the owner, the admin gets the deposit back
+        assert!(contract
+            .storage_balance_of("admin".try_into().unwrap())
+            .is_none());
     }
 
     #[test]
     #[should_panic(expected = "This method can be accessed only by the owner")]
-    fn panic_on_no_owner_accessing_raffle_event_prizes() {
+    fn panic_on_moderator_accessing_event_time() {
         let mut contract = Contract::new();
 
         let mut context = VMContextBuilder::new();
@@ -571,23 +2925,28 @@ mod tests {
         context.predecessor_account_id("owner".try_into().unwrap());
 
         testing_env!(context.build());
-        let event_id = contract.add_event(String::from("title"), Some(START_TIME), Some(END_TIME));
-
-        testing_env!(context.build());
-        contract.add_near_prize(event_id.clone(), U128(1_000_000_000_000_000_000_000_000));
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
 
         testing_env!(context.build());
-        contract.set_event_visible(event_id.clone());
+        contract.grant_event_role(
+            event_id.clone(),
+            "moderator".to_string(),
+            EventRole::Moderator,
+        );
 
         testing_env!(context
-            .predecessor_account_id("den".try_into().unwrap())
-            .block_timestamp(RAFFLE_TIME * 1_000_000)
+            .predecessor_account_id("moderator".try_into().unwrap())
             .build());
-        contract.raffle_event_prizes(event_id.clone());
+        contract.set_event_time(event_id.clone(), START_TIME, END_TIME);
     }
 
     #[test]
-    fn pass_owner_set_event_visible() {
+    fn pass_release_storage_on_revoking_event_role() {
         let mut contract = Contract::new();
 
         let mut context = VMContextBuilder::new();
@@ -596,18 +2955,46 @@ mod tests {
         context.predecessor_account_id("owner".try_into().unwrap());
 
         testing_env!(context.build());
-        let event_id = contract.add_event(String::from("title"), Some(START_TIME), Some(END_TIME));
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
 
-        testing_env!(context.build());
-        contract.add_near_prize(event_id.clone(), U128(1_000_000_000_000_000_000_000_000));
+        let available_before = contract
+            .storage_balance_of("owner".try_into().unwrap())
+            .unwrap()
+            .available;
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.grant_event_role(event_id, "den".to_string(), EventRole::Moderator);
+
+        assert!(
+            contract
+                .storage_balance_of("owner".try_into().unwrap())
+                .unwrap()
+                .available
+                .0
+                < available_before.0
+        );
 
-        testing_env!(context.build());
-        contract.set_event_visible(event_id.clone());
+        contract.revoke_event_role(event_id, "den".to_string());
+
+        // the yocto attached on grant stays in the storage balance
+        assert_eq!(
+            contract
+                .storage_balance_of("owner".try_into().unwrap())
+                .unwrap()
+                .available
+                .0,
+            available_before.0 + 1
+        );
     }
 
     #[test]
-    #[should_panic(expected = "Event status isn't 'Configuration'")]
-    fn panic_on_set_event_time_after_event_visible() {
+    #[should_panic(expected = "This method can be accessed only by the owner")]
+    fn panic_on_admin_granting_event_role() {
         let mut contract = Contract::new();
 
         let mut context = VMContextBuilder::new();
@@ -615,31 +3002,26 @@ mod tests {
         context.attached_deposit(2_000_000_000_000_000_000_000_000);
         context.predecessor_account_id("owner".try_into().unwrap());
 
-        context.block_timestamp(CURRENT_TIME * 1_000_000);
-
         testing_env!(context.build());
-        let event_id = contract.add_event(String::from("title"), None, None);
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
 
-        println!("event_id: {}", event_id);
-        let event = contract.get_event(event_id.clone()).unwrap();
-        println!("event_before_near_prize: {:?}", event);
-        contract.add_near_prize(event_id.clone(), U128(1_000_000_000_000_000_000_000_000));
-        let event = contract.get_event(event_id.clone()).unwrap();
-        println!("event_after_near_prize: {:?}", event);
-        contract.set_event_visible(event_id.clone());
+        testing_env!(context.build());
+        contract.grant_event_role(event_id.clone(), "admin".to_string(), EventRole::Admin);
 
-        let event = contract.get_event(event_id.clone()).unwrap();
-        println!("event_after_event_visible: {:?}", event);
-        contract.set_event_time(event_id.clone(), START_TIME, END_TIME);
-        let event = contract.get_event(event_id.clone()).unwrap();
-        println!("event_after_event_time: {:?}", event);
+        testing_env!(context
+            .predecessor_account_id("admin".try_into().unwrap())
+            .build());
+        contract.grant_event_role(event_id.clone(), "den".to_string(), EventRole::Admin);
     }
 
     #[test]
-    #[should_panic(
-        expected = "Prize amount couldn't be less than 100000000000000000000000 yoctoNear"
-    )]
-    fn panic_on_adding_small_near_prize_for_event() {
+    #[should_panic(expected = "Accounts with a role in the event can't participate in it")]
+    fn panic_on_joining_event_with_role() {
         let mut contract = Contract::new();
 
         let mut context = VMContextBuilder::new();
@@ -648,14 +3030,27 @@ mod tests {
         context.predecessor_account_id("owner".try_into().unwrap());
 
         testing_env!(context.build());
-        let event_id = contract.add_event(String::from("title"), Some(START_TIME), Some(END_TIME));
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
 
-        testing_env!(context.build());
-        contract.add_near_prize(event_id.clone(), U128(1_000_000));
+        contract.grant_event_role(event_id, "admin".to_string(), EventRole::Admin);
+        contract.add_near_prize(event_id, U128(1_000_000_000_000_000_000_000_000), None);
+        contract.set_event_visible(event_id);
+
+        testing_env!(context
+            .predecessor_account_id("admin".try_into().unwrap())
+            .block_timestamp(ACTIVE_TIME * 1_000_000)
+            .build());
+        contract.join_event(event_id);
     }
 
     #[test]
-    fn pass_adding_event_near_prize() {
+    #[should_panic(expected = "Accounts with a role in the event can't participate in it")]
+    fn panic_on_granting_event_role_to_participant() {
         let mut contract = Contract::new();
 
         let mut context = VMContextBuilder::new();
@@ -664,15 +3059,31 @@ mod tests {
         context.predecessor_account_id("owner".try_into().unwrap());
 
         testing_env!(context.build());
-        let event_id = contract.add_event(String::from("title"), Some(START_TIME), Some(END_TIME));
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
 
-        testing_env!(context.build());
-        contract.add_near_prize(event_id.clone(), U128(100_000_000_000_000_000_000_000));
+        contract.add_near_prize(event_id, U128(1_000_000_000_000_000_000_000_000), None);
+        contract.set_event_visible(event_id);
+
+        testing_env!(context
+            .predecessor_account_id("den".try_into().unwrap())
+            .block_timestamp(ACTIVE_TIME * 1_000_000)
+            .build());
+        contract.join_event(event_id);
+
+        testing_env!(context
+            .predecessor_account_id("owner".try_into().unwrap())
+            .build());
+        contract.grant_event_role(event_id, "den".to_string(), EventRole::Moderator);
     }
 
     #[test]
-    #[should_panic(expected = "Event status isn't 'Configuration'")]
-    fn panic_on_adding_near_prize_for_visible_event() {
+    #[should_panic(expected = "This method can be accessed only by the owner")]
+    fn panic_on_revoked_admin_accessing_event_visibility() {
         let mut contract = Contract::new();
 
         let mut context = VMContextBuilder::new();
@@ -681,20 +3092,35 @@ mod tests {
         context.predecessor_account_id("owner".try_into().unwrap());
 
         testing_env!(context.build());
-        let event_id = contract.add_event(String::from("title"), Some(START_TIME), Some(END_TIME));
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
 
         testing_env!(context.build());
-        contract.add_near_prize(event_id.clone(), U128(1_000_000_000_000_000_000_000_000));
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
+
         testing_env!(context.build());
-        contract.set_event_visible(event_id.clone());
+        contract.grant_event_role(event_id.clone(), "admin".to_string(), EventRole::Admin);
 
-        testing_env!(context.block_timestamp(CURRENT_TIME * 1_000_000).build());
-        contract.add_near_prize(event_id.clone(), U128(1_000_000_000_000_000_000_000_000));
+        testing_env!(context.attached_deposit(1).build());
+        contract.revoke_event_role(event_id.clone(), "admin".to_string());
+
+        testing_env!(context
+            .predecessor_account_id("admin".try_into().unwrap())
+            .build());
+        contract.set_event_visible(event_id.clone());
     }
 
     #[test]
-    #[should_panic(expected = "Event status isn't 'Active'")]
-    fn panic_on_joining_event_before_start() {
+    #[should_panic(expected = "You should attach at least")]
+    fn panic_on_joining_event_without_ticket_price() {
         let mut contract = Contract::new();
 
         let mut context = VMContextBuilder::new();
@@ -703,24 +3129,35 @@ mod tests {
         context.predecessor_account_id("owner".try_into().unwrap());
 
         testing_env!(context.build());
-        let event_id = contract.add_event(String::from("title"), Some(START_TIME), Some(END_TIME));
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.set_event_ticket_price(event_id.clone(), U128(5_000_000_000_000_000_000_000_000));
 
         testing_env!(context.build());
-        contract.add_near_prize(event_id.clone(), U128(1_000_000_000_000_000_000_000_000));
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
 
         testing_env!(context.build());
         contract.set_event_visible(event_id.clone());
 
         testing_env!(context
+            .block_timestamp(ACTIVE_TIME * 1_000_000)
             .predecessor_account_id("den".try_into().unwrap())
-            .block_timestamp((START_TIME - 1_000_000) * 1_000_000)
             .build());
         contract.join_event(event_id.clone());
     }
 
     #[test]
-    #[should_panic(expected = "Event status isn't 'Active'")]
-    fn panic_on_joining_event_after_end() {
+    fn pass_ban_event_participant() {
         let mut contract = Contract::new();
 
         let mut context = VMContextBuilder::new();
@@ -729,24 +3166,52 @@ mod tests {
         context.predecessor_account_id("owner".try_into().unwrap());
 
         testing_env!(context.build());
-        let event_id = contract.add_event(String::from("title"), Some(START_TIME), Some(END_TIME));
-
-        testing_env!(context.build());
-        contract.add_near_prize(event_id.clone(), U128(1_000_000_000_000_000_000_000_000));
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
 
-        testing_env!(context.build());
-        contract.set_event_visible(event_id.clone());
+        contract.set_event_ticket_price(event_id, U128(1_000));
+        contract.add_near_prize(event_id, U128(1_000_000_000_000_000_000_000_000), None);
+        contract.grant_event_role(event_id, "moderator".to_string(), EventRole::Moderator);
+        contract.set_event_visible(event_id);
 
         testing_env!(context
+            .block_timestamp(ACTIVE_TIME * 1_000_000)
             .predecessor_account_id("den".try_into().unwrap())
-            .block_timestamp((END_TIME + 1_000_000) * 1_000_000)
             .build());
-        contract.join_event(event_id.clone());
+        contract.join_event(event_id);
+
+        testing_env!(context
+            .predecessor_account_id("moderator".try_into().unwrap())
+            .build());
+        contract.ban_event_participant(event_id, "den".to_string());
+
+        assert_eq!(contract.get_event_participant_count(event_id), 0);
+        assert_eq!(
+            contract
+                .get_participant_events("den".to_string(), None)
+                .total,
+            0
+        );
+
+        let banned = contract.get_event_banned_accounts(event_id, None);
+
+        assert_eq!(banned.items, vec!["den".to_string()]);
+        assert_eq!(
+            get_logs().last().unwrap(),
+            &format!(
+                r#"EVENT_JSON:{{"standard":"raffler","version":"1.0.0","event":"ban_participant","data":[{{"event_id":{},"account_id":"den","was_participant":true}}]}}"#,
+                event_id
+            )
+        );
     }
 
     #[test]
-    #[should_panic(expected = "You're already participating in this event")]
-    fn panic_on_multiple_joining_event() {
+    #[should_panic(expected = "You're banned from this event")]
+    fn panic_on_joining_event_after_ban() {
         let mut contract = Contract::new();
 
         let mut context = VMContextBuilder::new();
@@ -755,25 +3220,29 @@ mod tests {
         context.predecessor_account_id("owner".try_into().unwrap());
 
         testing_env!(context.build());
-        let event_id = contract.add_event(String::from("title"), Some(START_TIME), Some(END_TIME));
-
-        testing_env!(context.build());
-        contract.add_near_prize(event_id.clone(), U128(1_000_000_000_000_000_000_000_000));
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
 
-        testing_env!(context.build());
-        contract.set_event_visible(event_id.clone());
+        contract.add_near_prize(event_id, U128(1_000_000_000_000_000_000_000_000), None);
+        contract.set_event_visible(event_id);
+        contract.ban_event_participant(event_id, "den".to_string());
 
         testing_env!(context
             .block_timestamp(ACTIVE_TIME * 1_000_000)
             .predecessor_account_id("den".try_into().unwrap())
             .build());
-        contract.join_event(event_id.clone());
-        contract.join_event(event_id.clone());
+        contract.join_event(event_id);
     }
 
     #[test]
-    #[should_panic(expected = "Owner can't participate his own events")]
-    fn panic_on_joining_owned_event() {
+    #[should_panic(
+        expected = "This method can be accessed only by the owner or accounts with a suitable role"
+    )]
+    fn panic_on_banning_by_participant() {
         let mut contract = Contract::new();
 
         let mut context = VMContextBuilder::new();
@@ -782,22 +3251,21 @@ mod tests {
         context.predecessor_account_id("owner".try_into().unwrap());
 
         testing_env!(context.build());
-        let event_id = contract.add_event(String::from("title"), Some(START_TIME), Some(END_TIME));
-
-        testing_env!(context.build());
-        contract.add_near_prize(event_id.clone(), U128(1_000_000_000_000_000_000_000_000));
-
-        testing_env!(context.build());
-        contract.set_event_visible(event_id.clone());
-
-        context.block_timestamp(ACTIVE_TIME * 1_000_000);
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
 
-        testing_env!(context.build());
-        contract.join_event(event_id.clone());
+        testing_env!(context
+            .predecessor_account_id("den".try_into().unwrap())
+            .build());
+        contract.ban_event_participant(event_id, "participant".to_string());
     }
 
     #[test]
-    fn pass_join_event() {
+    fn pass_join_event_sponsored_by_owner() {
         let mut contract = Contract::new();
 
         let mut context = VMContextBuilder::new();
@@ -806,24 +3274,54 @@ mod tests {
         context.predecessor_account_id("owner".try_into().unwrap());
 
         testing_env!(context.build());
-        let event_id = contract.add_event(String::from("title"), Some(START_TIME), Some(END_TIME));
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
 
         testing_env!(context.build());
-        contract.add_near_prize(event_id.clone(), U128(1_000_000_000_000_000_000_000_000));
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
 
-        testing_env!(context.build());
-        contract.set_event_visible(event_id.clone());
+        let join_storage_cost = contract.internal_join_storage_cost();
 
-        context.block_timestamp(ACTIVE_TIME * 1_000_000);
+        testing_env!(context.attached_deposit(join_storage_cost * 2).build());
+        contract.fund_event_storage(event_id.clone());
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.set_event_visible(event_id.clone());
 
+        // participant hasn't deposited anything for storage
         testing_env!(context
+            .block_timestamp(ACTIVE_TIME * 1_000_000)
             .predecessor_account_id("den".try_into().unwrap())
             .build());
         contract.join_event(event_id.clone());
+
+        assert!(contract.is_user_joined_event("den".to_string(), event_id.clone()));
+        assert!(contract.storage_balance_of("den".to_string()).is_none());
+        assert_eq!(contract.get_event_sponsored_joins(event_id.clone()), 1);
+
+        // the worst case cost is only a pre-check, the actual storage is debited
+        assert!(contract.internal_get_event(&event_id).storage_budget >= join_storage_cost);
+
+        // leftovers of budget are refunded at raffle time
+        testing_env!(context
+            .block_timestamp(RAFFLE_TIME * 1_000_000)
+            .predecessor_account_id("owner".try_into().unwrap())
+            .build());
+        contract.raffle_event_prizes(event_id.clone());
+
+        assert_eq!(contract.get_event_sponsored_joins(event_id.clone()), 0);
     }
 
     #[test]
-    fn pass_raffle_event_prizes_for_multiple_participants() {
+    fn pass_return_storage_of_banned_sponsored_participant_to_budget() {
         let mut contract = Contract::new();
 
         let mut context = VMContextBuilder::new();
@@ -832,40 +3330,60 @@ mod tests {
         context.predecessor_account_id("owner".try_into().unwrap());
 
         testing_env!(context.build());
-        let event_id = contract.add_event(String::from("title"), Some(START_TIME), Some(END_TIME));
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
 
         testing_env!(context.build());
-        contract.add_near_prize(event_id.clone(), U128(1_000_000_000_000_000_000_000_000));
+        contract.add_near_prize(event_id, U128(1_000_000_000_000_000_000_000_000), None);
 
-        testing_env!(context.build());
-        contract.set_event_visible(event_id.clone());
+        let join_storage_cost = contract.internal_join_storage_cost();
 
-        context.block_timestamp(ACTIVE_TIME * 1_000_000);
+        testing_env!(context.attached_deposit(join_storage_cost).build());
+        contract.fund_event_storage(event_id);
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.set_event_visible(event_id);
 
+        // the participant is registered, but the join is still sponsored
         testing_env!(context
+            .attached_deposit(10_000_000_000_000_000_000_000)
             .predecessor_account_id("den".try_into().unwrap())
             .build());
-        contract.join_event(event_id.clone());
+        contract.storage_deposit(None, None);
+
+        let den_balance = contract.storage_balance_of("den".to_string()).unwrap();
 
         testing_env!(context
-            .predecessor_account_id("den2".try_into().unwrap())
-            .build());
-        contract.join_event(event_id.clone());
-        testing_env!(context
-            .predecessor_account_id("den3".try_into().unwrap())
+            .attached_deposit(1)
+            .block_timestamp(ACTIVE_TIME * 1_000_000)
             .build());
-        contract.join_event(event_id.clone());
+        contract.join_event(event_id);
+
+        assert!(contract.internal_get_event(&event_id).storage_budget < join_storage_cost);
 
         testing_env!(context
             .predecessor_account_id("owner".try_into().unwrap())
-            .block_timestamp(RAFFLE_TIME * 1_000_000)
             .build());
-        let prizes = contract.raffle_event_prizes(event_id.clone());
+        contract.ban_event_participant(event_id, "den".to_string());
 
-        assert!(prizes.len() == 1);
+        // released storage isn't credited to the participant who hasn't paid for it
+        assert_eq!(
+            contract.storage_balance_of("den".to_string()).unwrap(),
+            den_balance
+        );
+        assert_eq!(
+            contract.internal_get_event(&event_id).storage_budget,
+            join_storage_cost
+        );
     }
+
     #[test]
-    fn pass_raffle_event_prizes() {
+    #[should_panic(expected = "Couldn't fund storage of the event which has already ended")]
+    fn panic_on_funding_storage_of_ended_event() {
         let mut contract = Contract::new();
 
         let mut context = VMContextBuilder::new();
@@ -874,40 +3392,56 @@ mod tests {
         context.predecessor_account_id("owner".try_into().unwrap());
 
         testing_env!(context.build());
-        let event_id = contract.add_event(String::from("title"), Some(START_TIME), Some(END_TIME));
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
 
         testing_env!(context.build());
-        contract.add_near_prize(event_id.clone(), U128(1_000_000_000_000_000_000_000_000));
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
 
         testing_env!(context.build());
         contract.set_event_visible(event_id.clone());
 
-        context.block_timestamp(ACTIVE_TIME * 1_000_000);
+        testing_env!(context.block_timestamp(RAFFLE_TIME * 1_000_000).build());
+        contract.fund_event_storage(event_id.clone());
+    }
 
-        testing_env!(context
-            .predecessor_account_id("den".try_into().unwrap())
-            .build());
-        contract.join_event(event_id.clone());
+    #[test]
+    #[should_panic(expected = "This method can be accessed only by the owner")]
+    fn panic_on_admin_funding_event_storage() {
+        let mut contract = Contract::new();
 
-        testing_env!(context
-            .predecessor_account_id("owner".try_into().unwrap())
-            .block_timestamp(RAFFLE_TIME * 1_000_000)
-            .build());
-        let prizes = contract.raffle_event_prizes(event_id.clone());
+        let mut context = VMContextBuilder::new();
 
-        assert!(prizes.len() == 1);
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
 
-        let json_event = contract.get_event(event_id).unwrap();
+        testing_env!(context.build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
 
-        let internal_prize = json_event.prizes.get(0).unwrap();
+        testing_env!(context.build());
+        contract.grant_event_role(event_id.clone(), "admin".to_string(), EventRole::Admin);
 
-        assert_eq!(internal_prize.claimed, false);
-        assert_eq!(internal_prize.winner_account_id, Some("den".to_string()));
+        testing_env!(context
+            .predecessor_account_id("admin".try_into().unwrap())
+            .build());
+        contract.fund_event_storage(event_id.clone());
     }
 
     #[test]
-    #[should_panic(expected = "Event has no participants")]
-    fn panic_on_raffle_event_prizes_without_participants() {
+    fn pass_archive_event_with_claimed_prizes() {
         let mut contract = Contract::new();
 
         let mut context = VMContextBuilder::new();
@@ -916,23 +3450,71 @@ mod tests {
         context.predecessor_account_id("owner".try_into().unwrap());
 
         testing_env!(context.build());
-        let event_id = contract.add_event(String::from("title"), Some(START_TIME), Some(END_TIME));
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
 
         testing_env!(context.build());
-        contract.add_near_prize(event_id.clone(), U128(1_000_000_000_000_000_000_000_000));
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
 
         testing_env!(context.build());
         contract.set_event_visible(event_id.clone());
 
+        testing_env!(context
+            .block_timestamp(ACTIVE_TIME * 1_000_000)
+            .predecessor_account_id("den".try_into().unwrap())
+            .build());
+        contract.join_event(event_id.clone());
+
+        let den_balance = contract.storage_balance_of("den".to_string()).unwrap();
+
         testing_env!(context
             .predecessor_account_id("owner".try_into().unwrap())
             .block_timestamp(RAFFLE_TIME * 1_000_000)
             .build());
-        contract.raffle_event_prizes(event_id.clone());
+        let prizes = contract.raffle_event_prizes(event_id.clone());
+
+        testing_env!(context
+            .predecessor_account_id("den".try_into().unwrap())
+            .block_timestamp(CLAIM_TIME * 1_000_000)
+            .build());
+        contract.claim_prize(prizes.get(0).unwrap().clone());
+
+        testing_env!(context
+            .predecessor_account_id("owner".try_into().unwrap())
+            .attached_deposit(1)
+            .build());
+        assert!(contract.archive_event(event_id.clone(), None));
+
+        let json_event = contract.get_event(event_id.clone()).unwrap();
+
+        assert_eq!(json_event.status, EventStatus::Archived);
+        assert_eq!(json_event.participants_amount, 1);
+        assert_eq!(json_event.prizes.get(0).unwrap().winners[0].claimed, true);
+
+        assert!(!contract.is_user_joined_event("den".to_string(), event_id.clone()));
+        assert_eq!(
+            contract.get_owner_events("owner".to_string(), None).total,
+            1
+        );
+
+        // archived event is only found by id
+        assert_eq!(contract.get_events(None, None, None).total, 0);
+
+        // storage of participation is released
+        let balance = contract.storage_balance_of("den".to_string()).unwrap();
+        assert!(balance.available.0 > den_balance.available.0);
     }
 
     #[test]
-    fn pass_claim_event_prize() {
+    fn pass_archive_event_in_batches() {
         let mut contract = Contract::new();
 
         let mut context = VMContextBuilder::new();
@@ -941,59 +3523,76 @@ mod tests {
         context.predecessor_account_id("owner".try_into().unwrap());
 
         testing_env!(context.build());
-        let event_id = contract.add_event(String::from("title"), Some(START_TIME), Some(END_TIME));
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
 
         testing_env!(context.build());
-        contract.add_near_prize(event_id.clone(), U128(1_000_000_000_000_000_000_000_000));
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
 
         testing_env!(context.build());
         contract.set_event_visible(event_id.clone());
 
         context.block_timestamp(ACTIVE_TIME * 1_000_000);
 
-        testing_env!(context
-            .predecessor_account_id("den".try_into().unwrap())
-            .build());
-        contract.join_event(event_id.clone());
+        for participant in ["den", "den2", "den3"] {
+            testing_env!(context
+                .predecessor_account_id(participant.try_into().unwrap())
+                .build());
+            contract.join_event(event_id.clone());
+        }
 
         testing_env!(context
             .predecessor_account_id("owner".try_into().unwrap())
             .block_timestamp(RAFFLE_TIME * 1_000_000)
             .build());
-        let prizes = contract.raffle_event_prizes(event_id.clone());
-
-        let den_prize = prizes.get(0).unwrap().clone();
+        contract.raffle_event_prizes(event_id.clone());
 
+        // unclaimed prize has expired
         testing_env!(context
-            .predecessor_account_id("den".try_into().unwrap())
-            .block_timestamp(CLAIM_TIME * 1_000_000)
+            .block_timestamp((END_TIME + PRIZE_CLAIM_PERIOD_MS) * 1_000_000)
+            .attached_deposit(1)
             .build());
+        assert!(!contract.archive_event(event_id.clone(), Some(2)));
 
-        let unclaimed = contract.get_account_unclaimed_prizes("den".to_string(), None);
-        assert_eq!(unclaimed.len(), 1);
-
-        contract.claim_prize(den_prize.clone());
-
-        let unclaimed = contract.get_account_unclaimed_prizes("den".to_string(), None);
-        assert_eq!(unclaimed.len(), 0);
-
-        let json_event = contract.get_event(event_id).unwrap();
+        assert_eq!(
+            contract.get_event(event_id.clone()).unwrap().status,
+            EventStatus::Archived
+        );
 
-        let internal_prize = json_event
-            .prizes
-            .get(den_prize.prize_index as usize)
-            .unwrap();
+        assert!(contract.archive_event(event_id.clone(), Some(2)));
+
+        for participant in ["den", "den2", "den3"] {
+            assert!(
+                !contract.is_user_joined_event(participant.try_into().unwrap(), event_id.clone())
+            );
+            assert_eq!(
+                contract
+                    .get_account_unclaimed_prizes(participant.try_into().unwrap(), None)
+                    .total,
+                0
+            );
+        }
 
-        assert_eq!(internal_prize.claimed, true);
         assert_eq!(
-            internal_prize.winner_account_id.as_deref(),
-            Some("den".to_string()).as_deref()
+            contract
+                .get_event(event_id.clone())
+                .unwrap()
+                .participants_amount,
+            3
         );
     }
 
     #[test]
-    #[should_panic(expected = "Couldn't claim prize since it doesn't exist")]
-    fn panic_on_claiming_another_event_prize() {
+    #[should_panic(expected = "Couldn't archive event until all prizes are claimed or expired")]
+    fn panic_on_archiving_event_with_unclaimed_prizes() {
         let mut contract = Contract::new();
 
         let mut context = VMContextBuilder::new();
@@ -1002,17 +3601,25 @@ mod tests {
         context.predecessor_account_id("owner".try_into().unwrap());
 
         testing_env!(context.build());
-        let event_id = contract.add_event(String::from("title"), Some(START_TIME), Some(END_TIME));
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
 
         testing_env!(context.build());
-        contract.add_near_prize(event_id.clone(), U128(1_000_000_000_000_000_000_000_000));
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
 
         testing_env!(context.build());
         contract.set_event_visible(event_id.clone());
 
-        context.block_timestamp(ACTIVE_TIME * 1_000_000);
-
         testing_env!(context
+            .block_timestamp(ACTIVE_TIME * 1_000_000)
             .predecessor_account_id("den".try_into().unwrap())
             .build());
         contract.join_event(event_id.clone());
@@ -1021,21 +3628,17 @@ mod tests {
             .predecessor_account_id("owner".try_into().unwrap())
             .block_timestamp(RAFFLE_TIME * 1_000_000)
             .build());
-        let prizes = contract.raffle_event_prizes(event_id.clone());
-
-        let den_prize = prizes.get(0).unwrap().clone();
+        contract.raffle_event_prizes(event_id.clone());
 
         testing_env!(context
-            .predecessor_account_id("another_den".try_into().unwrap())
             .block_timestamp(CLAIM_TIME * 1_000_000)
+            .attached_deposit(1)
             .build());
-
-        contract.claim_prize(den_prize);
+        contract.archive_event(event_id.clone(), None);
     }
 
     #[test]
-    #[should_panic(expected = "Couldn't claim prize since it doesn't exist")]
-    fn panic_on_claiming_non_existed_event_prize() {
+    fn pass_emit_logs_on_event_lifecycle() {
         let mut contract = Contract::new();
 
         let mut context = VMContextBuilder::new();
@@ -1044,37 +3647,91 @@ mod tests {
         context.predecessor_account_id("owner".try_into().unwrap());
 
         testing_env!(context.build());
-        let event_id = contract.add_event(String::from("title"), Some(START_TIME), Some(END_TIME));
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"raffler","version":"1.0.0","event":"add_event","data":[{"event_id":1,"owner_id":"owner","title":"title","started_at":2000000,"ended_at":4000000}]}"#
+            ]
+        );
+
+        testing_env!(context.build());
+        contract.set_event_time(event_id.clone(), START_TIME, RAFFLE_TIME);
+
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"raffler","version":"1.0.0","event":"set_event_time","data":[{"event_id":1,"started_at":2000000,"ended_at":5000000}]}"#
+            ]
+        );
 
         testing_env!(context.build());
-        contract.add_near_prize(event_id.clone(), U128(1_000_000_000_000_000_000_000_000));
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
+
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"raffler","version":"1.0.0","event":"add_near_prize","data":[{"event_id":1,"prize_index":0,"amount":"1000000000000000000000000","quantity":1}]}"#
+            ]
+        );
 
         testing_env!(context.build());
         contract.set_event_visible(event_id.clone());
 
-        context.block_timestamp(ACTIVE_TIME * 1_000_000);
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"raffler","version":"1.0.0","event":"set_event_visible","data":[{"event_id":1}]}"#
+            ]
+        );
 
         testing_env!(context
+            .block_timestamp(ACTIVE_TIME * 1_000_000)
             .predecessor_account_id("den".try_into().unwrap())
             .build());
         contract.join_event(event_id.clone());
 
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"raffler","version":"1.0.0","event":"join_event","data":[{"event_id":1,"account_id":"den"}]}"#
+            ]
+        );
+
         testing_env!(context
             .predecessor_account_id("owner".try_into().unwrap())
             .block_timestamp(RAFFLE_TIME * 1_000_000)
             .build());
-        contract.raffle_event_prizes(event_id.clone());
+        let prizes = contract.raffle_event_prizes(event_id.clone());
 
-        let non_existed_prize = EventPrize {
-            event_id: 14241241421,
-            prize_index: 4214124,
-        };
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"raffler","version":"1.0.0","event":"raffle_event_prizes","data":[{"event_id":1,"winners":[{"prize_index":0,"slot_index":0,"account_id":"den"}]}]}"#
+            ]
+        );
 
         testing_env!(context
             .predecessor_account_id("den".try_into().unwrap())
             .block_timestamp(CLAIM_TIME * 1_000_000)
             .build());
+        contract.claim_prize(prizes[0].clone());
 
-        contract.claim_prize(non_existed_prize);
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"raffler","version":"1.0.0","event":"claim_prize","data":[{"event_id":1,"prize_index":0,"slot_index":0,"account_id":"den","amount":"1000000000000000000000000"}]}"#
+            ]
+        );
     }
 }
@@ -1,16 +1,47 @@
+use super::utils::get_event_storage_prefix;
+use crate::admin::types::Fees;
+use crate::series::types::SeriesId;
 use crate::types::TimestampMs;
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
-use near_sdk::collections::{UnorderedSet, Vector};
-use near_sdk::json_types::U128;
+use near_sdk::collections::{TreeMap, UnorderedSet, Vector};
+use near_sdk::json_types::{Base64VecU8, U128};
 use near_sdk::{
     serde::{Deserialize, Serialize},
-    AccountId,
+    AccountId, Balance, StorageUsage,
 };
 use witgen::witgen;
 
 // min prize amount is 0.1N
 pub const MIN_NEAR_PRIZE_AMOUNT: u128 = 100_000_000_000_000_000_000_000;
 
+// prizes which weren't claimed within 30 days after the end of event are expired
+pub const PRIZE_CLAIM_PERIOD_MS: u64 = 30 * 24 * 3_600 * 1_000;
+
+// how many participants are released by a single 'archive_event' call by default
+pub const DEFAULT_ARCHIVE_BATCH_SIZE: u64 = 100;
+
+// events are read one by one to filter them by status, so a page of 'get_events' walks
+// a bounded part of the index & could be returned shorter than the limit
+pub const MAX_SCANNED_EVENTS_PER_PAGE: u64 = 200;
+
+// winners of all prizes are raffled in a single call, so their total in the event is bounded
+pub const MAX_WINNERS_PER_EVENT: u64 = 100;
+
+// every slot of the prize has its own winner, so the prize couldn't have more of them than the event
+pub const MAX_PRIZE_QUANTITY: u64 = MAX_WINNERS_PER_EVENT;
+
+// tickets of failed event are refunded in a single call, so the threshold is bounded
+pub const MAX_MIN_PARTICIPANTS: u64 = 100;
+
+// size limits of event metadata, everything is stored on-chain and paid by the owner
+pub const MAX_DESCRIPTION_LENGTH: usize = 2_048;
+pub const MAX_TERMS_LENGTH: usize = 2_048;
+pub const MAX_URL_LENGTH: usize = 256;
+pub const MAX_TAGS_PER_EVENT: usize = 5;
+pub const MAX_TAG_LENGTH: usize = 32;
+// media hash is sha256 of the content
+pub const MEDIA_HASH_LENGTH: usize = 32;
+
 #[witgen]
 pub type EventId = u64;
 
@@ -19,13 +50,131 @@ pub type EventId = u64;
 pub struct Event {
     pub id: EventId,
     pub owner_id: AccountId,
+    pub pending_owner_id: Option<AccountId>,
     pub title: String,
+    pub metadata: EventMetadata,
     pub is_visible: bool,
     pub started_at: TimestampMs,
     pub ended_at: TimestampMs,
     pub raffled: bool,
+    pub raffled_at: Option<TimestampMs>,
     pub participants: UnorderedSet<AccountId>,
-    pub prizes: Vector<Prize>,
+    pub banned: UnorderedSet<AccountId>, // couldn't join the event
+    pub prizes: Vector<VersionedPrize>,
+    pub roles: TreeMap<AccountId, EventRole>,
+    pub ticket_price: Balance,
+    pub ticket_sales: Balance, // paid out to the owner once prizes are raffled
+    pub min_participants: u64, // event fails on raffle with less participants
+    pub failed: bool,          // prizes & tickets were refunded instead of the raffle
+    pub fees: Fees,
+    pub storage_budget: Balance, // prefunded by the owner to cover storage of participants
+    pub storage_usage: StorageUsage, // charged to the owner, moved along with the ownership
+    pub sponsored: UnorderedSet<AccountId>, // participants whose storage was paid from the budget
+    pub series_id: Option<SeriesId>,
+    pub extension: u64, // how long 'ended_at' was extended while event was active
+    pub archived: bool, // participants are being released, event is removed afterwards
+}
+
+// every event is stored wrapped into this enum, 'Current' is the layout of this release,
+// only layouts which were deployed have their own variant, so before the next release
+// changes 'Event', this layout has to be frozen as a new variant with a 'From' conversion,
+// otherwise events stored by the deployed release couldn't be read anymore
+#[derive(BorshDeserialize, BorshSerialize)]
+pub enum VersionedEvent {
+    Current(Event),
+}
+
+impl From<VersionedEvent> for Event {
+    fn from(event: VersionedEvent) -> Self {
+        match event {
+            VersionedEvent::Current(e) => e,
+        }
+    }
+}
+
+impl From<Event> for VersionedEvent {
+    fn from(event: Event) -> Self {
+        VersionedEvent::Current(event)
+    }
+}
+
+// layout of events created before versioning was introduced,
+// they're stored without version tag under the legacy prefix
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct EventV1 {
+    pub id: EventId,
+    pub owner_id: AccountId,
+    pub title: String,
+    pub is_visible: bool,
+    pub started_at: TimestampMs,
+    pub ended_at: TimestampMs,
+    pub raffled: bool,
+    pub participants: UnorderedSet<AccountId>,
+    pub prizes: Vector<VersionedPrize>,
+}
+
+impl From<EventV1> for Event {
+    fn from(event: EventV1) -> Self {
+        Event {
+            id: event.id,
+            owner_id: event.owner_id,
+            pending_owner_id: None,
+            title: event.title,
+            metadata: EventMetadata::default(),
+            is_visible: event.is_visible,
+            started_at: event.started_at,
+            ended_at: event.ended_at,
+            raffled: event.raffled,
+            // wasn't tracked before
+            raffled_at: None,
+            participants: event.participants,
+            prizes: event.prizes,
+            roles: TreeMap::new(get_event_storage_prefix(b"er", &event.id)),
+            banned: UnorderedSet::new(get_event_storage_prefix(b"eb", &event.id)),
+            sponsored: UnorderedSet::new(get_event_storage_prefix(b"es", &event.id)),
+            ticket_price: 0,
+            ticket_sales: 0,
+            min_participants: 0,
+            failed: false,
+            fees: Fees::default(),
+            storage_budget: 0,
+            // wasn't tracked before, so the storage stays charged to the creator
+            storage_usage: 0,
+            series_id: None,
+            extension: 0,
+            archived: false,
+        }
+    }
+}
+
+#[witgen]
+#[derive(
+    BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Default,
+)]
+#[serde(crate = "near_sdk::serde")]
+pub struct EventMetadata {
+    pub description: Option<String>,
+    pub media: Option<String>,           // URL of the cover image
+    pub media_hash: Option<Base64VecU8>, // sha256 of the cover image, required along with 'media'
+    pub link: Option<String>,            // URL of the external page of event
+    pub tags: Vec<String>,
+    pub terms: Option<String>, // terms of participation
+}
+
+// compact record which is kept instead of the archived event
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct EventSummary {
+    pub id: EventId,
+    pub owner_id: AccountId,
+    pub title: String,
+    pub started_at: TimestampMs,
+    pub ended_at: TimestampMs,
+    pub raffled_at: Option<TimestampMs>,
+    pub participants_amount: u64,
+    pub prizes: Vec<VersionedPrize>,
+    pub series_id: Option<SeriesId>,
+    pub ticket_price: Balance,
+    pub fees: Fees,
 }
 
 #[witgen]
@@ -34,12 +183,20 @@ pub struct Event {
 pub struct JsonEvent {
     pub id: EventId,
     pub title: String,
+    pub metadata: Option<EventMetadata>, // isn't kept for archived events
     pub started_at: TimestampMs,
     pub ended_at: TimestampMs,
     pub status: EventStatus,
+    pub series_id: Option<SeriesId>,
+    pub raffled_at: Option<TimestampMs>,
     pub prizes: Vec<Prize>,
     pub participants_amount: u64,
     pub owner_id: AccountId,
+    pub verified: bool, // owner is a verified creator
+    pub pending_owner_id: Option<AccountId>,
+    pub ticket_price: U128,
+    pub min_participants: u64,
+    pub fees: Fees,
 }
 
 #[witgen]
@@ -51,6 +208,8 @@ pub enum EventStatus {
     Active,        // anyone who met coniditions is able to join event
     Raffling,      // can't join, owner can raffle prizes
     Claiming,      // nothing is able
+    Failed,        // not enough participants, prizes & tickets are refunded
+    Archived,      // prizes are settled, only summary of event is kept
 }
 
 impl std::fmt::Display for EventStatus {
@@ -61,16 +220,89 @@ impl std::fmt::Display for EventStatus {
             EventStatus::Active => write!(f, "Active"),
             EventStatus::Raffling => write!(f, "Raffling"),
             EventStatus::Claiming => write!(f, "Claiming"),
+            EventStatus::Failed => write!(f, "Failed"),
+            EventStatus::Archived => write!(f, "Archived"),
         }
     }
 }
 
 #[witgen]
-#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum EventOrder {
+    StartedAt,
+    EndedAt,
+}
+
+#[witgen]
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum EventRole {
+    Admin,     // can change params and raffle prizes
+    Moderator, // can manage participants (allowlists, bans)
+}
+
+impl EventRole {
+    pub fn has_permission(&self, permission: &EventPermission) -> bool {
+        match *self {
+            EventRole::Admin => true,
+            EventRole::Moderator => *permission == EventPermission::Moderate,
+        }
+    }
+}
+
+impl std::fmt::Display for EventRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            EventRole::Admin => write!(f, "Admin"),
+            EventRole::Moderator => write!(f, "Moderator"),
+        }
+    }
+}
+
+// actions on event which can be delegated to accounts with roles,
+// the owner is always allowed to perform all of them
+#[derive(PartialEq)]
+pub enum EventPermission {
+    Configure,
+    Raffle,
+    Moderate,
+}
+
+#[witgen]
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonEventRole {
+    pub account_id: AccountId,
+    pub role: EventRole,
+}
+
+#[witgen]
+#[derive(
+    BorshDeserialize,
+    BorshSerialize,
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+)]
 #[serde(crate = "near_sdk::serde", tag = "type")]
 pub struct EventPrize {
     pub event_id: EventId,
     pub prize_index: u64,
+    #[serde(default)]
+    pub slot_index: u64, // prize with quantity has a winner per slot
+}
+
+// key of unclaimed prize before slots were introduced, a prize had the only winner
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct EventPrizeV1 {
+    pub event_id: EventId,
+    pub prize_index: u64,
 }
 
 #[witgen]
@@ -78,6 +310,35 @@ pub struct EventPrize {
 #[serde(crate = "near_sdk::serde", tag = "type")]
 pub enum PrizeType {
     NEAR { amount: U128 },
+    SplitPot { amount: U128, split: PrizeSplit }, // pool which is shared among winners
+}
+
+// prize of 'create_event_full', winners of 'SplitPot' are given by its split, so
+// 'quantity' (1 by default) applies to 'NEAR' prizes only
+#[witgen]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PrizeDefinition {
+    pub prize_type: PrizeType,
+    pub quantity: Option<u64>,
+}
+
+// how the pool of 'SplitPot' prize is shared, a winner is raffled for every share
+#[witgen]
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(crate = "near_sdk::serde", tag = "type")]
+pub enum PrizeSplit {
+    Equal { winners: u64 },
+    Percentage { shares_bps: Vec<u32> }, // in basis points, should sum up to 10_000
+}
+
+impl PrizeSplit {
+    pub fn winners(&self) -> u64 {
+        match self {
+            PrizeSplit::Equal { winners } => *winners,
+            PrizeSplit::Percentage { shares_bps } => shares_bps.len() as u64,
+        }
+    }
 }
 
 #[witgen]
@@ -85,6 +346,81 @@ pub enum PrizeType {
 #[serde(crate = "near_sdk::serde")]
 pub struct Prize {
     pub prize_type: PrizeType,
+    pub quantity: u64, // how many winners get the prize, each of them gets the whole prize
+    pub winners: Vec<PrizeWinner>, // by slot, filled once prizes are raffled
+}
+
+#[witgen]
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PrizeWinner {
+    pub account_id: AccountId,
+    pub amount: U128, // share of the prize won by this slot
+    pub claimed: bool,
+    pub claimed_at: Option<TimestampMs>,
+}
+
+impl Prize {
+    pub fn is_claimed(&self) -> bool {
+        self.winners.iter().all(|winner| winner.claimed)
+    }
+}
+
+// the same rules as for 'VersionedEvent' apply, 'V1' is the layout of the first release
+#[derive(BorshDeserialize, BorshSerialize, Clone)]
+pub enum VersionedPrize {
+    V1(PrizeV1),
+    Current(Prize),
+}
+
+impl From<VersionedPrize> for Prize {
+    fn from(prize: VersionedPrize) -> Self {
+        match prize {
+            VersionedPrize::V1(p) => Prize {
+                prize_type: PrizeType::NEAR { amount: p.amount },
+                quantity: 1,
+                winners: p
+                    .winner_account_id
+                    .map(|account_id| PrizeWinner {
+                        account_id,
+                        amount: p.amount,
+                        claimed: p.claimed,
+                        claimed_at: None,
+                    })
+                    .into_iter()
+                    .collect(),
+            },
+            VersionedPrize::Current(p) => p,
+        }
+    }
+}
+
+impl From<Prize> for VersionedPrize {
+    fn from(prize: Prize) -> Self {
+        VersionedPrize::Current(prize)
+    }
+}
+
+// layout of prizes created before versioning was introduced, they were stored
+// without version tag, but since 'PrizeType::NEAR' was the only (first) prize type,
+// their bytes are exactly the same as of 'VersionedPrize::V1'
+#[derive(BorshDeserialize, BorshSerialize, Clone)]
+pub struct PrizeV1 {
+    pub amount: U128,
     pub winner_account_id: Option<AccountId>,
     pub claimed: bool,
 }
+
+#[witgen]
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonEventWinner {
+    pub prize_index: u64,
+    pub slot_index: u64,
+    pub prize_type: PrizeType,
+    pub amount: U128,
+    pub winner_account_id: AccountId,
+    pub blocked: bool, // blocked winner couldn't claim the prize, it's returned to the owner on expiration
+    pub claimed: bool,
+    pub claimed_at: Option<TimestampMs>,
+}
@@ -1,24 +1,162 @@
-use super::types::{Event, EventStatus, JsonEvent};
+use super::types::{
+    Event, EventId, EventMetadata, EventPermission, EventStatus, EventSummary, JsonEvent, Prize,
+    PrizeSplit, PrizeType, MAX_DESCRIPTION_LENGTH, MAX_PRIZE_QUANTITY, MAX_TAGS_PER_EVENT,
+    MAX_TAG_LENGTH, MAX_TERMS_LENGTH, MAX_URL_LENGTH, MEDIA_HASH_LENGTH,
+};
+use crate::errors::RafflerError;
+use crate::types::BASIS_POINTS;
 use crate::utils::get_random_number;
 use crate::utils::{assert_condition, current_time_ms};
-use near_sdk::{env, AccountId};
+use near_sdk::json_types::U128;
+use near_sdk::{env, AccountId, Balance};
+use std::collections::HashMap;
+
+pub(super) fn get_event_storage_prefix(prefix: &[u8], event_id: &EventId) -> Vec<u8> {
+    let mut storage_prefix = Vec::with_capacity(prefix.len() + 32);
+
+    storage_prefix.extend(prefix);
+    storage_prefix.extend(env::sha256(&event_id.to_be_bytes()));
+
+    storage_prefix
+}
 
 pub(super) fn assert_event_owner(event: &Event) {
     assert_condition(
         event.owner_id == env::predecessor_account_id(),
-        "This method can be accessed only by the owner",
+        RafflerError::NotEventOwner,
     );
 }
 
+pub(super) fn assert_event_permission(event: &Event, permission: EventPermission) {
+    let account_id = env::predecessor_account_id();
+
+    let is_permitted = event.owner_id == account_id
+        || event
+            .roles
+            .get(&account_id)
+            .is_some_and(|role| role.has_permission(&permission));
+
+    assert_condition(is_permitted, RafflerError::NoEventPermission);
+}
+
 pub(super) fn assert_event_status(event: &Event, status: EventStatus) {
     let event_status = get_event_status(event);
 
-    let msg = format!("Event status isn't '{}'", &status);
+    assert_condition(
+        event_status == status,
+        RafflerError::InvalidEventStatus {
+            event_id: event.id,
+            expected: status,
+            actual: event_status,
+        },
+    );
+}
+
+pub(super) fn assert_event_metadata(metadata: &EventMetadata) {
+    assert_text_length("description", &metadata.description, MAX_DESCRIPTION_LENGTH);
+    assert_text_length("terms", &metadata.terms, MAX_TERMS_LENGTH);
 
-    assert_condition(event_status == status, msg);
+    assert_url("media", &metadata.media);
+    assert_url("link", &metadata.link);
+
+    assert_condition(
+        metadata.media.is_some() == metadata.media_hash.is_some(),
+        RafflerError::InvalidMediaHash,
+    );
+
+    if let Some(media_hash) = &metadata.media_hash {
+        assert_condition(
+            media_hash.0.len() == MEDIA_HASH_LENGTH,
+            RafflerError::InvalidMediaHash,
+        );
+    }
+
+    assert_condition(
+        metadata.tags.len() <= MAX_TAGS_PER_EVENT,
+        RafflerError::TooManyTags {
+            max: MAX_TAGS_PER_EVENT,
+        },
+    );
+
+    for tag in metadata.tags.iter() {
+        assert_condition(
+            !tag.is_empty() && tag.len() <= MAX_TAG_LENGTH,
+            RafflerError::InvalidTagLength {
+                max: MAX_TAG_LENGTH,
+            },
+        );
+    }
+}
+
+fn assert_text_length(field: &'static str, text: &Option<String>, max: usize) {
+    if let Some(text) = text {
+        assert_condition(
+            text.len() <= max,
+            RafflerError::MetadataFieldTooLong { field, max },
+        );
+    }
+}
+
+fn assert_url(field: &'static str, url: &Option<String>) {
+    assert_text_length(field, url, MAX_URL_LENGTH);
+
+    if let Some(url) = url {
+        assert_condition(
+            url.starts_with("https://") || url.starts_with("ipfs://"),
+            RafflerError::InvalidMetadataUrl { field },
+        );
+    }
+}
+
+pub(super) fn assert_prize_split(split: &PrizeSplit) {
+    let is_valid = match split {
+        PrizeSplit::Equal { winners } => (1..=MAX_PRIZE_QUANTITY).contains(winners),
+        PrizeSplit::Percentage { shares_bps } => {
+            (1..=MAX_PRIZE_QUANTITY).contains(&split.winners())
+                && shares_bps.iter().all(|bps| *bps > 0)
+                && shares_bps.iter().map(|bps| u64::from(*bps)).sum::<u64>()
+                    == u64::from(BASIS_POINTS)
+        }
+    };
+
+    assert_condition(
+        is_valid,
+        RafflerError::InvalidPrizeSplit {
+            max: MAX_PRIZE_QUANTITY,
+        },
+    );
+}
+
+// how much is deposited for the prize with all of its slots
+pub(super) fn get_prize_total_amount(prize_type: &PrizeType, quantity: u64) -> Balance {
+    match prize_type {
+        PrizeType::NEAR { amount } => amount.0 * Balance::from(quantity),
+        PrizeType::SplitPot { amount, .. } => amount.0,
+    }
+}
+
+// how much the winner of every slot gets, shares of split pot are rounded down,
+// so they could sum up to a bit less than the pool
+pub(super) fn get_prize_slot_amounts(prize: &Prize) -> Vec<Balance> {
+    match &prize.prize_type {
+        PrizeType::NEAR { amount } => vec![amount.0; prize.quantity as usize],
+        PrizeType::SplitPot { amount, split } => match split {
+            PrizeSplit::Equal { winners } => {
+                vec![amount.0 / Balance::from(*winners); *winners as usize]
+            }
+            PrizeSplit::Percentage { shares_bps } => shares_bps
+                .iter()
+                .map(|bps| amount.0 * Balance::from(*bps) / Balance::from(BASIS_POINTS))
+                .collect(),
+        },
+    }
 }
 
 pub(super) fn get_event_status(event: &Event) -> EventStatus {
+    if event.archived {
+        return EventStatus::Archived;
+    }
+
     if !event.is_visible {
         return EventStatus::Configuration;
     }
@@ -40,34 +178,89 @@ pub(super) fn get_event_status(event: &Event) -> EventStatus {
         return EventStatus::Raffling;
     }
 
+    if event.failed {
+        return EventStatus::Failed;
+    }
+
     return EventStatus::Claiming;
 }
 
-pub(super) fn get_random_event_participant_id(event: &Event) -> AccountId {
-    // @todo change this function to produce u64 randoms
-    let seed = get_random_number(0) as u64;
+// picks distinct participants, so there could be less of them than requested
+pub(super) fn get_random_event_participant_ids(
+    event: &Event,
+    prize_index: u64,
+    amount: u64,
+) -> Vec<AccountId> {
+    assert_condition(
+        !event.participants.is_empty(),
+        RafflerError::EventWithoutParticipants { event_id: event.id },
+    );
+
+    let participants_amount = event.participants.len();
+
+    let mut participant_indexes: Vec<u64> = Vec::new();
+
+    // partial Fisher-Yates shuffle, the slot takes a random one of participants which haven't
+    // won yet, only swapped positions are kept instead of the whole list
+    let mut swapped: HashMap<u64, u64> = HashMap::new();
+
+    for slot_index in 0..amount.min(participants_amount) {
+        let seed = get_random_number(&[prize_index, slot_index]);
 
-    assert_condition(event.participants.len() > 0, "Event has no participants");
+        let index = slot_index + seed % (participants_amount - slot_index);
 
-    let random_participant_index = seed % event.participants.len();
+        let participant_index = *swapped.get(&index).unwrap_or(&index);
 
-    event
-        .participants
-        .as_vector()
-        .get(random_participant_index)
-        .unwrap()
+        swapped.insert(index, *swapped.get(&slot_index).unwrap_or(&slot_index));
+
+        participant_indexes.push(participant_index);
+    }
+
+    participant_indexes
+        .into_iter()
+        .map(|index| event.participants.as_vector().get(index).unwrap())
+        .collect()
 }
 
-pub(super) fn get_event_json(event: &Event) -> JsonEvent {
+pub(super) fn get_event_json(event: &Event, verified: bool) -> JsonEvent {
     JsonEvent {
         id: event.id.clone(),
         title: event.title.clone(),
+        metadata: Some(event.metadata.clone()),
         started_at: event.started_at,
         ended_at: event.ended_at,
-        prizes: event.prizes.to_vec(),
+        prizes: event.prizes.iter().map(Prize::from).collect(),
         status: get_event_status(event),
+        series_id: event.series_id,
+        raffled_at: event.raffled_at,
         participants_amount: event.participants.len(),
         owner_id: event.owner_id.clone(),
+        verified,
+        pending_owner_id: event.pending_owner_id.clone(),
+        ticket_price: U128(event.ticket_price),
+        min_participants: event.min_participants,
+        fees: event.fees.clone(),
+    }
+}
+
+pub(super) fn get_event_summary_json(summary: &EventSummary, verified: bool) -> JsonEvent {
+    JsonEvent {
+        id: summary.id,
+        title: summary.title.clone(),
+        metadata: None,
+        started_at: summary.started_at,
+        ended_at: summary.ended_at,
+        prizes: summary.prizes.iter().cloned().map(Prize::from).collect(),
+        status: EventStatus::Archived,
+        series_id: summary.series_id,
+        raffled_at: summary.raffled_at,
+        participants_amount: summary.participants_amount,
+        owner_id: summary.owner_id.clone(),
+        verified,
+        pending_owner_id: None,
+        ticket_price: U128(summary.ticket_price),
+        min_participants: 0,
+        fees: summary.fees.clone(),
     }
 }
 
@@ -90,7 +283,7 @@ mod tests {
 
         testing_env!(context.build());
 
-        let event_id = contract.add_event(String::from("title"), None, None);
+        let event_id = contract.add_event(String::from("title"), None, None, None);
 
         let event = contract.internal_get_event(&event_id);
 
@@ -109,7 +302,7 @@ mod tests {
 
         testing_env!(context.build());
 
-        let event_id = contract.add_event(String::from("title"), None, None);
+        let event_id = contract.add_event(String::from("title"), None, None, None);
 
         let event = contract.internal_get_event(&event_id);
 
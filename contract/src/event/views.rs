@@ -1,11 +1,17 @@
-use crate::types::Pagination;
-use crate::utils::unwrap_pagination;
+use crate::errors::RafflerError;
+use crate::types::{CursorPagination, Page};
+use crate::utils::{
+    get_from_bound, get_key_page, get_merged_key_page, get_page, get_vector_page, unwrap_pagination,
+};
 use crate::*;
-use near_sdk::collections::UnorderedSet;
+use near_sdk::collections::TreeMap;
 use near_sdk::{AccountId};
+use std::ops::Bound;
 
-use super::types::{EventId, EventPrize, JsonEvent};
-use super::utils::get_event_json;
+use super::types::{
+    EventId, EventOrder, EventPrize, EventStatus, JsonEvent, JsonEventRole, JsonEventWinner,
+    MAX_SCANNED_EVENTS_PER_PAGE,
+};
 use witgen::witgen;
 
 #[near_bindgen]
@@ -14,89 +20,301 @@ impl Contract {
     pub fn get_owner_events(
         &self,
         account_id: AccountId,
-        pagination: Option<Pagination>,
-    ) -> Vec<JsonEvent> {
+        pagination: Option<CursorPagination<EventId>>,
+    ) -> Page<JsonEvent, EventId> {
         let event_ids = self
             .events_by_owner
             .get(&account_id)
             // we don't need here build prefix because this is view method
-            .unwrap_or(UnorderedSet::new(Vec::new()));
-
-        let (_skip, _take) = unwrap_pagination(pagination);
-
-        event_ids
-            .iter()
-            .skip(_skip)
-            .take(_take)
-            .filter_map(|event_id| self.events.get(&event_id))
-            .map(|e| get_event_json(&e))
-            .collect::<Vec<JsonEvent>>()
+            .unwrap_or(TreeMap::new(Vec::new()));
+
+        let page = get_key_page(&event_ids, pagination, self.config.max_pagination_limit);
+
+        Page {
+            items: page
+                .items
+                .iter()
+                .filter_map(|event_id| self.internal_find_event_json(event_id))
+                .collect::<Vec<JsonEvent>>(),
+            next_key: page.next_key,
+            total: page.total,
+        }
     }
 
     #[witgen]
     pub fn get_participant_events(
         &self,
         account_id: AccountId,
-        pagination: Option<Pagination>,
-    ) -> Vec<JsonEvent> {
+        pagination: Option<CursorPagination<EventId>>,
+    ) -> Page<JsonEvent, EventId> {
         let event_ids = self
             .events_by_participant
             .get(&account_id)
             // we don't need here build prefix because this is view method
-            .unwrap_or(UnorderedSet::new(Vec::new()));
-
-        let (_skip, _take) = unwrap_pagination(pagination);
+            .unwrap_or(TreeMap::new(Vec::new()));
+
+        // events of the first release are kept in the legacy mapping
+        let page = get_merged_key_page(
+            &event_ids,
+            self.internal_get_legacy_participant_events(&account_id),
+            pagination,
+            self.config.max_pagination_limit,
+        );
 
-        event_ids
-            .iter()
-            .skip(_skip)
-            .take(_take)
-            .filter_map(|event_id| self.events.get(&event_id))
-            .map(|e| get_event_json(&e))
-            .collect::<Vec<JsonEvent>>()
+        Page {
+            items: page
+                .items
+                .iter()
+                .filter_map(|event_id| self.internal_find_event_json(event_id))
+                .collect::<Vec<JsonEvent>>(),
+            next_key: page.next_key,
+            total: page.total,
+        }
     }
 
+    // lists events which were made visible & aren't archived, the earliest come first, the page
+    // starts right after the event given by 'from_key', 'total' counts listed events of all statuses
     #[witgen]
-    pub fn get_event(&self, event_id: EventId) -> Option<JsonEvent> {
-        let event = self.events.get(&event_id);
+    pub fn get_events(
+        &self,
+        status: Option<EventStatus>,
+        order_by: Option<EventOrder>,
+        pagination: Option<CursorPagination<EventId>>,
+    ) -> Page<JsonEvent, EventId> {
+        let (from_event_id, limit) =
+            unwrap_pagination(pagination, self.config.max_pagination_limit);
+
+        let order_by = order_by.unwrap_or(EventOrder::StartedAt);
+
+        let visible_events = match order_by {
+            EventOrder::StartedAt => &self.visible_events_by_start,
+            EventOrder::EndedAt => &self.visible_events_by_end,
+        };
+
+        // the key in the index is restored from the time of event
+        let from_key = from_event_id.map(|event_id| {
+            let event = self
+                .internal_find_event_json(&event_id)
+                .unwrap_or_else(|| RafflerError::EventNotFound { event_id }.panic());
+
+            match order_by {
+                EventOrder::StartedAt => (event.started_at, event_id),
+                EventOrder::EndedAt => (event.ended_at, event_id),
+            }
+        });
+
+        // the index is walked in order, so only events up to the end of page are read
+        let mut event_ids = visible_events
+            .range((get_from_bound(visible_events, from_key), Bound::Unbounded))
+            .map(|((_, event_id), _)| event_id);
+
+        let max_scanned = limit.max(MAX_SCANNED_EVENTS_PER_PAGE as usize);
+
+        let mut items: Vec<JsonEvent> = Vec::new();
+        let mut last_scanned_id = None;
+
+        for event_id in event_ids.by_ref().take(max_scanned) {
+            last_scanned_id = Some(event_id);
+
+            if let Some(event) = self
+                .internal_find_event_json(&event_id)
+                .filter(|e| status.as_ref().is_none_or(|s| &e.status == s))
+            {
+                items.push(event);
+
+                if items.len() == limit {
+                    break;
+                }
+            }
+        }
 
-        match event {
-            Some(e) => Some(get_event_json(&e)),
-            None => None,
+        // the next page starts after the last scanned event, even if it was filtered out
+        let next_key = last_scanned_id.filter(|_| event_ids.next().is_some());
+
+        Page {
+            items,
+            next_key,
+            total: visible_events.len(),
         }
     }
 
+    #[witgen]
+    pub fn get_event(&self, event_id: EventId) -> Option<JsonEvent> {
+        self.internal_find_event_json(&event_id)
+    }
+
     #[witgen]
     pub fn get_account_unclaimed_prizes(
         &self,
         account_id: AccountId,
-        pagination: Option<Pagination>,
-    ) -> Vec<EventPrize> {
+        pagination: Option<CursorPagination<EventPrize>>,
+    ) -> Page<EventPrize, EventPrize> {
         let prizes = self
             .unclaimed_prizes_by_account
             .get(&account_id)
             // we don't need here to build prefix because this is view method
-            .unwrap_or(UnorderedSet::new(Vec::new()));
+            .unwrap_or(TreeMap::new(Vec::new()));
 
-        let (_skip, _take) = unwrap_pagination(pagination);
+        get_key_page(&prizes, pagination, self.config.max_pagination_limit)
+    }
 
-        prizes
-            .iter()
-            .skip(_skip)
-            .take(_take)
-            .collect::<Vec<EventPrize>>()
+    #[witgen]
+    pub fn get_event_roles(
+        &self,
+        event_id: EventId,
+        pagination: Option<CursorPagination<AccountId>>,
+    ) -> Page<JsonEventRole, AccountId> {
+        let event = match self.internal_find_event(&event_id) {
+            Some(e) => e,
+            None => {
+                return Page {
+                    items: Vec::new(),
+                    next_key: None,
+                    total: 0,
+                }
+            }
+        };
+
+        let page = get_page(&event.roles, pagination, self.config.max_pagination_limit);
+
+        Page {
+            items: page
+                .items
+                .into_iter()
+                .map(|(account_id, role)| JsonEventRole { account_id, role })
+                .collect::<Vec<JsonEventRole>>(),
+            next_key: page.next_key,
+            total: page.total,
+        }
     }
 
+    // how many more accounts are able to join the event without paying for storage
     #[witgen]
-    pub fn is_user_joined_event(&self, account_id: AccountId, event_id: EventId) -> bool {
-        let wrapped_joined_events = self.events_by_participant.get(&account_id);
+    pub fn get_event_sponsored_joins(&self, event_id: EventId) -> u64 {
+        let event = match self.internal_find_event(&event_id) {
+            Some(e) => e,
+            None => return 0,
+        };
+
+        (event.storage_budget / self.internal_join_storage_cost()) as u64
+    }
+
+    // prizes which have winners, so it's empty until the event is raffled
+    #[witgen]
+    pub fn get_event_winners(&self, event_id: EventId) -> Vec<JsonEventWinner> {
+        let event = match self.internal_find_event_json(&event_id) {
+            Some(e) => e,
+            None => return Vec::new(),
+        };
+
+        event
+            .prizes
+            .into_iter()
+            .enumerate()
+            .flat_map(|(prize_index, prize)| {
+                let prize_type = prize.prize_type;
+
+                prize
+                    .winners
+                    .into_iter()
+                    .enumerate()
+                    .map(move |(slot_index, winner)| {
+                        (prize_index, slot_index, prize_type.clone(), winner)
+                    })
+            })
+            .map(
+                |(prize_index, slot_index, prize_type, winner)| JsonEventWinner {
+                    prize_index: prize_index as u64,
+                    slot_index: slot_index as u64,
+                    prize_type,
+                    amount: winner.amount,
+                    blocked: self.internal_is_blocked(&winner.account_id),
+                    winner_account_id: winner.account_id,
+                    claimed: winner.claimed,
+                    claimed_at: winner.claimed_at,
+                },
+            )
+            .collect()
+    }
+
+    // participants are kept by the index of the draw, so the page is keyed by it, a ban
+    // before the draw moves the last participant to the place of the banned one
+    #[witgen]
+    pub fn get_event_participants(
+        &self,
+        event_id: EventId,
+        pagination: Option<CursorPagination<u64>>,
+    ) -> Page<AccountId, u64> {
+        let event = match self.internal_find_event(&event_id) {
+            Some(e) => e,
+            None => {
+                return Page {
+                    items: Vec::new(),
+                    next_key: None,
+                    total: 0,
+                }
+            }
+        };
+
+        get_vector_page(
+            event.participants.as_vector(),
+            pagination,
+            self.config.max_pagination_limit,
+        )
+    }
+
+    #[witgen]
+    pub fn get_event_banned_accounts(
+        &self,
+        event_id: EventId,
+        pagination: Option<CursorPagination<u64>>,
+    ) -> Page<AccountId, u64> {
+        let event = match self.internal_find_event(&event_id) {
+            Some(e) => e,
+            None => {
+                return Page {
+                    items: Vec::new(),
+                    next_key: None,
+                    total: 0,
+                }
+            }
+        };
+
+        get_vector_page(
+            event.banned.as_vector(),
+            pagination,
+            self.config.max_pagination_limit,
+        )
+    }
 
-        let joined_events = match wrapped_joined_events {
+    #[witgen]
+    pub fn get_event_participant_count(&self, event_id: EventId) -> u64 {
+        self.internal_find_event(&event_id)
+            .map_or(0, |event| event.participants.len())
+    }
+
+    // results are in the same order as provided accounts
+    #[witgen]
+    pub fn are_participants(&self, event_id: EventId, account_ids: Vec<AccountId>) -> Vec<bool> {
+        let event = match self.internal_find_event(&event_id) {
             Some(e) => e,
-            None => return false,
+            None => return vec![false; account_ids.len()],
         };
 
-        joined_events.contains(&event_id)
+        account_ids
+            .iter()
+            .map(|account_id| event.participants.contains(account_id))
+            .collect()
+    }
+
+    #[witgen]
+    pub fn is_user_joined_event(&self, account_id: AccountId, event_id: EventId) -> bool {
+        let joined = self
+            .events_by_participant
+            .get(&account_id)
+            .is_some_and(|joined_events| joined_events.contains_key(&event_id));
+
+        joined || self.internal_is_legacy_participant(&account_id, &event_id)
     }
 }
 
@@ -104,7 +322,10 @@ impl Contract {
 mod tests {
     use near_sdk::test_utils::VMContextBuilder;
 
-    use crate::types::Pagination;
+    use crate::event::types::{
+        EventId, EventOrder, EventPrize, EventRole, EventStatus, MAX_SCANNED_EVENTS_PER_PAGE,
+    };
+    use crate::types::CursorPagination;
 
     use super::Contract;
 
@@ -130,25 +351,25 @@ mod tests {
         testing_env!(context.build());
         let events = contract.get_owner_events("owner".to_string(), None);
         // he should has one event
-        assert_eq!(events.len(), 0);
+        assert_eq!(events.items.len(), 0);
 
-        let event_id = contract.add_event(String::from("title"), None, None);
+        let event_id = contract.add_event(String::from("title"), None, None, None);
 
         context.is_view(true);
         testing_env!(context.build());
         let events = contract.get_owner_events("owner".to_string(), None);
 
         // should has one event
-        assert_eq!(events.len(), 1);
+        assert_eq!(events.items.len(), 1);
 
-        let event = &events[0];
+        let event = &events.items[0];
 
         // the same event_id as we created
         assert_eq!(event.id.clone(), event_id.clone());
 
         let events = contract.get_owner_events("no_owner".to_string(), None);
         // should has no events
-        assert_eq!(events.len(), 0);
+        assert_eq!(events.items.len(), 0);
     }
 
     #[test]
@@ -166,27 +387,123 @@ mod tests {
                 .predecessor_account_id("owner".try_into().unwrap())
                 .build());
 
-            contract.add_event(String::from("title"), Some(START_TIME), Some(END_TIME));
+            contract.add_event(
+                String::from("title"),
+                Some(START_TIME),
+                Some(END_TIME),
+                None,
+            );
         }
 
         let events = contract.get_owner_events("owner".to_string(), None);
-        assert_eq!(events.len(), 5);
+        assert_eq!(events.items.len(), 5);
+
+        let events = contract.get_owner_events(
+            "owner".to_string(),
+            Some(CursorPagination {
+                from_key: None,
+                limit: 5,
+            }),
+        );
+        assert_eq!(events.items.len(), 5);
+        assert_eq!(events.next_key, Some(5));
+        assert_eq!(events.total, 9);
+
+        let events = contract.get_owner_events(
+            "owner".to_string(),
+            Some(CursorPagination {
+                from_key: events.next_key,
+                limit: 5,
+            }),
+        );
+        assert_eq!(events.items.len(), 4);
+        assert_eq!(events.items[0].id, 6);
+        assert_eq!(events.next_key, None);
+        assert_eq!(events.total, 9);
+        let events = contract.get_owner_events(
+            "owner".to_string(),
+            Some(CursorPagination {
+                from_key: Some(10),
+                limit: 5,
+            }),
+        );
+        assert_eq!(events.items.len(), 0);
 
-        let events =
-            contract.get_owner_events("owner".to_string(), Some(Pagination { page: 1, limit: 5 }));
-        assert_eq!(events.len(), 5);
+        // couldn't return more than 5 per request
+        let events = contract.get_owner_events(
+            "owner".to_string(),
+            Some(CursorPagination {
+                from_key: None,
+                limit: 25,
+            }),
+        );
+        assert_eq!(events.items.len(), 5);
+    }
 
-        let events =
-            contract.get_owner_events("owner".to_string(), Some(Pagination { page: 2, limit: 5 }));
-        assert_eq!(events.len(), 4);
-        let events =
-            contract.get_owner_events("owner".to_string(), Some(Pagination { page: 3, limit: 5 }));
-        assert_eq!(events.len(), 0);
+    #[test]
+    fn pass_keep_next_page_after_removal() {
+        let mut contract = Contract::new();
 
-        // couldn't return more than 5 per request
-        let events =
-            contract.get_owner_events("owner".to_string(), Some(Pagination { page: 1, limit: 25 }));
-        assert_eq!(events.len(), 5);
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        for _ in 0..4 {
+            contract.add_event(
+                String::from("title"),
+                Some(START_TIME),
+                Some(END_TIME),
+                None,
+            );
+        }
+
+        let events = contract.get_owner_events(
+            "owner".to_string(),
+            Some(CursorPagination {
+                from_key: None,
+                limit: 2,
+            }),
+        );
+        assert_eq!(events.next_key, Some(2));
+
+        // the first event leaves the list before the next page is read
+        testing_env!(context.attached_deposit(1).build());
+        contract.propose_event_owner(1, Some("den".to_string()));
+
+        testing_env!(context
+            .predecessor_account_id("den".try_into().unwrap())
+            .attached_deposit(100_000_000_000_000_000_000_000)
+            .build());
+        contract.accept_event_owner(1);
+
+        let events = contract.get_owner_events(
+            "owner".to_string(),
+            Some(CursorPagination {
+                from_key: events.next_key,
+                limit: 2,
+            }),
+        );
+        assert_eq!(
+            events.items.iter().map(|e| e.id).collect::<Vec<EventId>>(),
+            vec![3, 4]
+        );
+        assert_eq!(events.next_key, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Pagination limit should be at least 1")]
+    fn panic_on_zero_pagination_limit() {
+        let contract = Contract::new();
+
+        contract.get_owner_events(
+            "owner".to_string(),
+            Some(CursorPagination {
+                from_key: None,
+                limit: 0,
+            }),
+        );
     }
 
     #[test]
@@ -199,16 +516,25 @@ mod tests {
         context.predecessor_account_id("owner".try_into().unwrap());
 
         testing_env!(context.build());
-        let event_id = contract.add_event(String::from("title"), Some(START_TIME), Some(END_TIME));
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
 
         testing_env!(context.build());
-        contract.add_near_prize(event_id.clone(), U128(1_000_000_000_000_000_000_000_000));
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
 
         testing_env!(context.build());
         contract.set_event_visible(event_id.clone());
 
         let participation_events = contract.get_participant_events("den".to_string(), None);
-        assert_eq!(participation_events.len(), 0);
+        assert_eq!(participation_events.items.len(), 0);
 
         testing_env!(context
             .block_timestamp(ACTIVE_TIME * 1_000_000)
@@ -219,10 +545,10 @@ mod tests {
         context.is_view(true);
 
         let participation_events = contract.get_participant_events("den".to_string(), None);
-        assert_eq!(participation_events.len(), 1);
+        assert_eq!(participation_events.items.len(), 1);
 
         let participation_events = contract.get_participant_events("den2".to_string(), None);
-        assert_eq!(participation_events.len(), 0);
+        assert_eq!(participation_events.items.len(), 0);
     }
 
     #[test]
@@ -238,11 +564,19 @@ mod tests {
                 .block_timestamp(CURRENT_TIME * 1_000_000)
                 .predecessor_account_id("owner".try_into().unwrap())
                 .build());
-            let event_id =
-                contract.add_event(String::from("title"), Some(START_TIME), Some(END_TIME));
+            let event_id = contract.add_event(
+                String::from("title"),
+                Some(START_TIME),
+                Some(END_TIME),
+                None,
+            );
 
             testing_env!(context.build());
-            contract.add_near_prize(event_id.clone(), U128(1_000_000_000_000_000_000_000_000));
+            contract.add_near_prize(
+                event_id.clone(),
+                U128(1_000_000_000_000_000_000_000_000),
+                None,
+            );
 
             testing_env!(context.build());
             contract.set_event_visible(event_id.clone());
@@ -258,21 +592,41 @@ mod tests {
 
         context.is_view(true);
 
-        let participation_events = contract
-            .get_participant_events("den".to_string(), Some(Pagination { page: 1, limit: 5 }));
-        assert_eq!(participation_events.len(), 5);
+        let participation_events = contract.get_participant_events(
+            "den".to_string(),
+            Some(CursorPagination {
+                from_key: None,
+                limit: 5,
+            }),
+        );
+        assert_eq!(participation_events.items.len(), 5);
 
-        let participation_events = contract
-            .get_participant_events("den".to_string(), Some(Pagination { page: 2, limit: 5 }));
-        assert_eq!(participation_events.len(), 4);
+        let participation_events = contract.get_participant_events(
+            "den".to_string(),
+            Some(CursorPagination {
+                from_key: participation_events.next_key,
+                limit: 5,
+            }),
+        );
+        assert_eq!(participation_events.items.len(), 4);
 
-        let participation_events = contract
-            .get_participant_events("den".to_string(), Some(Pagination { page: 3, limit: 5 }));
-        assert_eq!(participation_events.len(), 0);
+        let participation_events = contract.get_participant_events(
+            "den".to_string(),
+            Some(CursorPagination {
+                from_key: Some(10),
+                limit: 5,
+            }),
+        );
+        assert_eq!(participation_events.items.len(), 0);
 
-        let participation_events = contract
-            .get_participant_events("den".to_string(), Some(Pagination { page: 1, limit: 25 }));
-        assert_eq!(participation_events.len(), 5);
+        let participation_events = contract.get_participant_events(
+            "den".to_string(),
+            Some(CursorPagination {
+                from_key: None,
+                limit: 25,
+            }),
+        );
+        assert_eq!(participation_events.items.len(), 5);
     }
 
     #[test]
@@ -285,10 +639,19 @@ mod tests {
         context.predecessor_account_id("owner".try_into().unwrap());
 
         testing_env!(context.build());
-        let event_id = contract.add_event(String::from("title"), Some(START_TIME), Some(END_TIME));
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
 
         testing_env!(context.build());
-        contract.add_near_prize(event_id.clone(), U128(1_000_000_000_000_000_000_000_000));
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
 
         testing_env!(context.build());
         contract.set_event_visible(event_id.clone());
@@ -300,7 +663,7 @@ mod tests {
         contract.join_event(event_id.clone());
 
         let prizes = contract.get_account_unclaimed_prizes("den".to_string(), None);
-        assert_eq!(prizes.len(), 0);
+        assert_eq!(prizes.items.len(), 0);
 
         testing_env!(context
             .predecessor_account_id("owner".try_into().unwrap())
@@ -313,10 +676,10 @@ mod tests {
         testing_env!(context.block_timestamp(CLAIM_TIME * 1_000_000).build());
 
         let prizes = contract.get_account_unclaimed_prizes("den".to_string(), None);
-        assert_eq!(prizes.len(), 1);
+        assert_eq!(prizes.items.len(), 1);
 
         let prizes = contract.get_account_unclaimed_prizes("owner".to_string(), None);
-        assert_eq!(prizes.len(), 0);
+        assert_eq!(prizes.items.len(), 0);
     }
 
     #[test]
@@ -332,11 +695,19 @@ mod tests {
                 .block_timestamp(CURRENT_TIME * 1_000_000)
                 .predecessor_account_id("owner".try_into().unwrap())
                 .build());
-            let event_id =
-                contract.add_event(String::from("title"), Some(START_TIME), Some(END_TIME));
+            let event_id = contract.add_event(
+                String::from("title"),
+                Some(START_TIME),
+                Some(END_TIME),
+                None,
+            );
 
             testing_env!(context.build());
-            contract.add_near_prize(event_id.clone(), U128(1_000_000_000_000_000_000_000_000));
+            contract.add_near_prize(
+                event_id.clone(),
+                U128(1_000_000_000_000_000_000_000_000),
+                None,
+            );
 
             testing_env!(context.build());
             contract.set_event_visible(event_id.clone());
@@ -360,27 +731,82 @@ mod tests {
 
         let prizes = contract.get_account_unclaimed_prizes(
             "den".to_string(),
-            Some(Pagination { page: 1, limit: 5 }),
+            Some(CursorPagination {
+                from_key: None,
+                limit: 5,
+            }),
         );
-        assert_eq!(prizes.len(), 5);
+        assert_eq!(prizes.items.len(), 5);
 
         let prizes = contract.get_account_unclaimed_prizes(
             "den".to_string(),
-            Some(Pagination { page: 2, limit: 5 }),
+            Some(CursorPagination {
+                from_key: prizes.next_key,
+                limit: 5,
+            }),
         );
-        assert_eq!(prizes.len(), 4);
+        assert_eq!(prizes.items.len(), 4);
+        assert!(prizes.next_key.is_none());
 
         let prizes = contract.get_account_unclaimed_prizes(
             "den".to_string(),
-            Some(Pagination { page: 3, limit: 5 }),
+            Some(CursorPagination {
+                from_key: Some(EventPrize {
+                    event_id: 10,
+                    prize_index: 0,
+                    slot_index: 0,
+                }),
+                limit: 5,
+            }),
         );
-        assert_eq!(prizes.len(), 0);
+        assert_eq!(prizes.items.len(), 0);
 
         let prizes = contract.get_account_unclaimed_prizes(
             "den".to_string(),
-            Some(Pagination { page: 1, limit: 25 }),
+            Some(CursorPagination {
+                from_key: None,
+                limit: 25,
+            }),
         );
-        assert_eq!(prizes.len(), 5);
+        assert_eq!(prizes.items.len(), 5);
+    }
+
+    #[test]
+    fn pass_get_event_roles() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(String::from("title"), None, None, None);
+
+        let roles = contract.get_event_roles(event_id.clone(), None);
+        assert_eq!(roles.items.len(), 0);
+
+        testing_env!(context.build());
+        contract.grant_event_role(event_id.clone(), "admin".to_string(), EventRole::Admin);
+
+        testing_env!(context.build());
+        contract.grant_event_role(
+            event_id.clone(),
+            "moderator".to_string(),
+            EventRole::Moderator,
+        );
+
+        context.is_view(true);
+        testing_env!(context.build());
+
+        let roles = contract.get_event_roles(event_id.clone(), None);
+        assert_eq!(roles.items.len(), 2);
+
+        assert_eq!(roles.items[0].account_id, "admin".to_string());
+        assert_eq!(roles.items[0].role, EventRole::Admin);
+        assert_eq!(roles.items[1].account_id, "moderator".to_string());
+        assert_eq!(roles.items[1].role, EventRole::Moderator);
+        assert_eq!(roles.next_key, None);
     }
 
     #[test]
@@ -394,7 +820,7 @@ mod tests {
         testing_env!(context
             .attached_deposit(1_000_000_000_000_000_000_000_000)
             .build());
-        let event_id = contract.add_event(String::from("title"), None, None);
+        let event_id = contract.add_event(String::from("title"), None, None, None);
         context.is_view(true);
 
         testing_env!(context
@@ -415,11 +841,343 @@ mod tests {
         testing_env!(context
             .attached_deposit(1_000_000_000_000_000_000_000_000)
             .build());
-        let event_id = contract.add_event(String::from("title"), None, None);
+        let event_id = contract.add_event(String::from("title"), None, None, None);
         context.is_view(true);
 
         testing_env!(context.build());
         let event = contract.get_event(event_id);
         assert!(event.is_some(), "Event doesn't exist");
     }
+
+    #[test]
+    fn pass_get_event_sponsored_joins() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context
+            .attached_deposit(1_000_000_000_000_000_000_000_000)
+            .build());
+        let event_id = contract.add_event(String::from("title"), None, None, None);
+
+        assert_eq!(contract.get_event_sponsored_joins(event_id.clone()), 0);
+
+        let join_storage_cost = contract.internal_join_storage_cost();
+
+        testing_env!(context.attached_deposit(join_storage_cost * 3 + 1).build());
+        contract.fund_event_storage(event_id.clone());
+
+        assert_eq!(contract.get_event_sponsored_joins(event_id.clone()), 3);
+    }
+
+    #[test]
+    fn pass_get_events() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let first_event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+        let second_event_id = contract.add_event(
+            String::from("title"),
+            Some(1_500_000),
+            Some(RAFFLE_TIME),
+            None,
+        );
+        // event in configuration isn't listed
+        let third_event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        for event_id in [first_event_id, second_event_id, third_event_id] {
+            contract.add_near_prize(
+                event_id.clone(),
+                U128(1_000_000_000_000_000_000_000_000),
+                None,
+            );
+        }
+
+        contract.set_event_visible(first_event_id.clone());
+        contract.set_event_visible(second_event_id.clone());
+
+        context.is_view(true);
+        testing_env!(context.block_timestamp(END_TIME * 1_000_000).build());
+
+        let events = contract.get_events(None, None, None);
+        assert_eq!(events.items.len(), 2);
+        assert_eq!(events.items[0].id, second_event_id);
+        assert_eq!(events.items[1].id, first_event_id);
+
+        let events = contract.get_events(None, Some(EventOrder::EndedAt), None);
+        assert_eq!(events.items[0].id, first_event_id);
+        assert_eq!(events.items[1].id, second_event_id);
+
+        let events = contract.get_events(Some(EventStatus::Active), None, None);
+        assert_eq!(events.items.len(), 1);
+        assert_eq!(events.items[0].id, second_event_id);
+
+        let events = contract.get_events(Some(EventStatus::Raffling), None, None);
+        assert_eq!(events.items.len(), 1);
+        assert_eq!(events.items[0].id, first_event_id);
+
+        let events = contract.get_events(Some(EventStatus::Configuration), None, None);
+        assert_eq!(events.items.len(), 0);
+
+        let events = contract.get_events(
+            None,
+            None,
+            Some(CursorPagination {
+                from_key: None,
+                limit: 1,
+            }),
+        );
+        assert_eq!(events.next_key, Some(second_event_id));
+
+        let events = contract.get_events(
+            None,
+            None,
+            Some(CursorPagination {
+                from_key: events.next_key,
+                limit: 1,
+            }),
+        );
+        assert_eq!(events.items.len(), 1);
+        assert_eq!(events.items[0].id, first_event_id);
+        assert_eq!(events.next_key, None);
+    }
+
+    #[test]
+    fn pass_bound_scanned_events_of_filtered_page() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        for _ in 0..MAX_SCANNED_EVENTS_PER_PAGE + 1 {
+            testing_env!(context.build());
+            let event_id = contract.add_event(
+                String::from("title"),
+                Some(START_TIME),
+                Some(END_TIME),
+                None,
+            );
+
+            contract.add_near_prize(event_id, U128(1_000_000_000_000_000_000_000_000), None);
+            contract.set_event_visible(event_id);
+        }
+
+        // none of events match, so the page is empty, but not the last one
+        let events = contract.get_events(Some(EventStatus::Raffling), None, None);
+        assert_eq!(events.items.len(), 0);
+        assert_eq!(events.next_key, Some(MAX_SCANNED_EVENTS_PER_PAGE));
+        assert_eq!(events.total, MAX_SCANNED_EVENTS_PER_PAGE + 1);
+
+        let events = contract.get_events(
+            Some(EventStatus::Raffling),
+            None,
+            Some(CursorPagination {
+                from_key: events.next_key,
+                limit: 10,
+            }),
+        );
+        assert_eq!(events.items.len(), 0);
+        assert_eq!(events.next_key, None);
+    }
+
+    #[test]
+    fn pass_reorder_events_by_end_once_ended() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let first_event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+        let second_event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(RAFFLE_TIME),
+            None,
+        );
+
+        for event_id in [first_event_id, second_event_id] {
+            contract.add_near_prize(event_id, U128(1_000_000_000_000_000_000_000_000), None);
+            contract.set_event_visible(event_id);
+        }
+
+        let events = contract.get_events(None, Some(EventOrder::EndedAt), None);
+        assert_eq!(events.items[0].id, first_event_id);
+        assert_eq!(events.items[1].id, second_event_id);
+
+        testing_env!(context.block_timestamp(ACTIVE_TIME * 1_000_000).build());
+        contract.end_event(second_event_id);
+
+        let events = contract.get_events(None, Some(EventOrder::EndedAt), None);
+        assert_eq!(events.items.len(), 2);
+        assert_eq!(events.items[0].id, second_event_id);
+        assert_eq!(events.items[1].id, first_event_id);
+    }
+
+    #[test]
+    fn pass_get_event_participants() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.set_event_visible(event_id.clone());
+
+        context.block_timestamp(ACTIVE_TIME * 1_000_000);
+
+        for participant in ["den", "den2", "den3"] {
+            testing_env!(context
+                .predecessor_account_id(participant.try_into().unwrap())
+                .build());
+            contract.join_event(event_id.clone());
+        }
+
+        context.is_view(true);
+        testing_env!(context.build());
+
+        assert_eq!(contract.get_event_participant_count(event_id.clone()), 3);
+
+        let participants = contract.get_event_participants(
+            event_id.clone(),
+            Some(CursorPagination {
+                from_key: None,
+                limit: 2,
+            }),
+        );
+        assert_eq!(
+            participants.items,
+            vec!["den".to_string(), "den2".to_string()]
+        );
+        assert_eq!(participants.next_key, Some(1));
+        assert_eq!(participants.total, 3);
+
+        let participants = contract.get_event_participants(
+            event_id.clone(),
+            Some(CursorPagination {
+                from_key: participants.next_key,
+                limit: 2,
+            }),
+        );
+        assert_eq!(participants.items, vec!["den3".to_string()]);
+        assert_eq!(participants.next_key, None);
+
+        let joined = contract.are_participants(
+            event_id.clone(),
+            vec!["den2".to_string(), "owner".to_string()],
+        );
+        assert_eq!(joined, vec![true, false]);
+
+        assert_eq!(contract.get_event_participant_count(100), 0);
+        assert_eq!(
+            contract.are_participants(100, vec!["den".to_string()]),
+            vec![false]
+        );
+    }
+
+    #[test]
+    fn pass_get_event_winners() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.set_event_visible(event_id.clone());
+
+        testing_env!(context
+            .block_timestamp(ACTIVE_TIME * 1_000_000)
+            .predecessor_account_id("den".try_into().unwrap())
+            .build());
+        contract.join_event(event_id.clone());
+
+        // no winners before raffle
+        assert_eq!(contract.get_event_winners(event_id.clone()).len(), 0);
+
+        testing_env!(context
+            .predecessor_account_id("owner".try_into().unwrap())
+            .block_timestamp(RAFFLE_TIME * 1_000_000)
+            .build());
+        let prizes = contract.raffle_event_prizes(event_id.clone());
+
+        let event = contract.get_event(event_id.clone()).unwrap();
+        assert_eq!(event.raffled_at, Some(RAFFLE_TIME));
+
+        let winners = contract.get_event_winners(event_id.clone());
+        assert_eq!(winners.len(), 1);
+        assert_eq!(winners[0].prize_index, 0);
+        assert_eq!(winners[0].winner_account_id, "den".to_string());
+        assert_eq!(winners[0].claimed, false);
+        assert_eq!(winners[0].claimed_at, None);
+
+        testing_env!(context
+            .predecessor_account_id("den".try_into().unwrap())
+            .block_timestamp(CLAIM_TIME * 1_000_000)
+            .build());
+        contract.claim_prize(prizes[0].clone());
+
+        let winners = contract.get_event_winners(event_id.clone());
+        assert_eq!(winners[0].claimed, true);
+        assert_eq!(winners[0].claimed_at, Some(CLAIM_TIME));
+    }
 }
@@ -1,26 +1,53 @@
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
-use near_sdk::collections::{LookupMap, UnorderedSet};
-use near_sdk::{near_bindgen, AccountId, PanicOnDefault};
+use near_sdk::collections::{LookupMap, TreeMap};
+use near_sdk::{env, near_bindgen, AccountId, Balance, PanicOnDefault, StorageUsage};
 
 use witgen::witgen;
 
 mod utils;
 // import all modules
+mod admin;
+mod errors; // errors with stable codes, which are rendered into panic messages
 mod event;
+mod logs;
+mod series;
+mod storage;
 mod types; // common types that can be used anywhere in the app
+mod upgrade;
 
-use event::types::{Event, EventId, EventPrize};
+use admin::types::{Config, CreatorProfile};
+use event::types::{EventId, EventPrize, EventSummary, VersionedEvent};
+use series::types::{Series, SeriesId};
+use storage::types::AccountStorage;
+use types::TimestampMs;
 use utils::to_storage_key;
 
 #[witgen]
 #[near_bindgen]
 #[derive(BorshDeserialize, BorshSerialize, PanicOnDefault)]
 pub struct Contract {
+    admin_id: AccountId,
+    paused: bool,
+    config: Config,
+    blocked_accounts: TreeMap<AccountId, ()>,
+    blocked_subaccounts: TreeMap<AccountId, ()>,
+    verified_creators: TreeMap<AccountId, CreatorProfile>,
+    accrued_fees: Balance,
     next_event_id: EventId,
-    events: LookupMap<EventId, Event>,
-    events_by_owner: LookupMap<AccountId, UnorderedSet<EventId>>,
-    events_by_participant: LookupMap<AccountId, UnorderedSet<EventId>>,
-    unclaimed_prizes_by_account: LookupMap<AccountId, UnorderedSet<EventPrize>>,
+    events: LookupMap<EventId, VersionedEvent>,
+    archived_events: LookupMap<EventId, EventSummary>,
+    // visible events ordered by start & end time, the event id makes keys unique
+    visible_events_by_start: TreeMap<(TimestampMs, EventId), ()>,
+    visible_events_by_end: TreeMap<(TimestampMs, EventId), ()>,
+    // ordered, so pages of them aren't shifted by removals
+    events_by_owner: LookupMap<AccountId, TreeMap<EventId, ()>>,
+    events_by_participant: LookupMap<AccountId, TreeMap<EventId, ()>>,
+    unclaimed_prizes_by_account: LookupMap<AccountId, TreeMap<EventPrize, ()>>,
+    next_series_id: SeriesId,
+    series: LookupMap<SeriesId, Series>,
+    storage_accounts: LookupMap<AccountId, AccountStorage>,
+    account_storage_usage: StorageUsage,
+    join_storage_usage: StorageUsage,
 }
 
 #[near_bindgen]
@@ -28,12 +55,33 @@ impl Contract {
     #[witgen]
     #[init]
     pub fn new() -> Self {
-        Self {
+        let mut this = Self {
+            // account which deploys & initializes the contract becomes its admin
+            admin_id: env::predecessor_account_id(),
+            paused: false,
+            config: Config::default(),
+            blocked_accounts: TreeMap::new(to_storage_key("ba")),
+            blocked_subaccounts: TreeMap::new(to_storage_key("bs")),
+            verified_creators: TreeMap::new(to_storage_key("vc")),
+            accrued_fees: 0,
             next_event_id: 1,
-            events: LookupMap::new(to_storage_key("e")),
-            events_by_owner: LookupMap::new(to_storage_key("eo")),
-            events_by_participant: LookupMap::new(to_storage_key("ep")),
-            unclaimed_prizes_by_account: LookupMap::new(to_storage_key("upa")),
-        }
+            events: LookupMap::new(to_storage_key("ve")),
+            archived_events: LookupMap::new(to_storage_key("ae")),
+            visible_events_by_start: TreeMap::new(to_storage_key("vbs")),
+            visible_events_by_end: TreeMap::new(to_storage_key("vbe")),
+            events_by_owner: LookupMap::new(to_storage_key("to")),
+            events_by_participant: LookupMap::new(to_storage_key("tp")),
+            unclaimed_prizes_by_account: LookupMap::new(to_storage_key("tu")),
+            next_series_id: 1,
+            series: LookupMap::new(to_storage_key("s")),
+            storage_accounts: LookupMap::new(to_storage_key("sa")),
+            account_storage_usage: 0,
+            join_storage_usage: 0,
+        };
+
+        this.measure_account_storage_usage();
+        this.measure_join_storage_usage();
+
+        this
     }
 }
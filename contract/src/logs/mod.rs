@@ -0,0 +1,2 @@
+// export modules
+pub mod types; // NEP-297 logs emitted on state changes
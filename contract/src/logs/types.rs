@@ -0,0 +1,172 @@
+use crate::event::types::{EventId, PrizeSplit};
+use crate::series::types::SeriesId;
+use crate::types::TimestampMs;
+use near_sdk::json_types::U128;
+use near_sdk::serde::Serialize;
+use near_sdk::serde_json;
+use near_sdk::{env, AccountId};
+
+pub const LOG_STANDARD_NAME: &str = "raffler";
+// should be bumped on any breaking change of logs data
+pub const LOG_STANDARD_VERSION: &str = "1.0.0";
+
+#[derive(Serialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RafflerLog {
+    pub standard: &'static str,
+    pub version: &'static str,
+    #[serde(flatten)]
+    pub event: RafflerLogEvent,
+}
+
+// every variant is serialized as 'event' (name in snake case) and 'data' fields
+#[derive(Serialize, Debug)]
+#[serde(
+    crate = "near_sdk::serde",
+    tag = "event",
+    content = "data",
+    rename_all = "snake_case"
+)]
+pub enum RafflerLogEvent {
+    AddEvent(Vec<AddEventLog>),
+    AddSeries(Vec<AddSeriesLog>),
+    SetEventTime(Vec<SetEventTimeLog>),
+    ExtendEvent(Vec<ExtendEventLog>),
+    EndEvent(Vec<EndEventLog>),
+    SetEventVisible(Vec<SetEventVisibleLog>),
+    AddNearPrize(Vec<AddNearPrizeLog>),
+    AddSplitPotPrize(Vec<AddSplitPotPrizeLog>),
+    JoinEvent(Vec<JoinEventLog>),
+    BanParticipant(Vec<BanParticipantLog>),
+    RaffleEventPrizes(Vec<RaffleEventPrizesLog>),
+    FailEvent(Vec<FailEventLog>),
+    ClaimPrize(Vec<ClaimPrizeLog>),
+}
+
+impl RafflerLogEvent {
+    pub fn emit(self) {
+        let log = RafflerLog {
+            standard: LOG_STANDARD_NAME,
+            version: LOG_STANDARD_VERSION,
+            event: self,
+        };
+
+        env::log_str(&format!(
+            "EVENT_JSON:{}",
+            serde_json::to_string(&log).unwrap()
+        ));
+    }
+}
+
+#[derive(Serialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AddEventLog {
+    pub event_id: EventId,
+    pub owner_id: AccountId,
+    pub title: String,
+    pub started_at: TimestampMs,
+    pub ended_at: TimestampMs,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AddSeriesLog {
+    pub series_id: SeriesId,
+    pub owner_id: AccountId,
+    pub title: String,
+    pub occurrences: u64,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct SetEventTimeLog {
+    pub event_id: EventId,
+    pub started_at: TimestampMs,
+    pub ended_at: TimestampMs,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ExtendEventLog {
+    pub event_id: EventId,
+    pub ended_at: TimestampMs,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct EndEventLog {
+    pub event_id: EventId,
+    pub ended_at: TimestampMs,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct SetEventVisibleLog {
+    pub event_id: EventId,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AddNearPrizeLog {
+    pub event_id: EventId,
+    pub prize_index: u64,
+    pub amount: U128,
+    pub quantity: u64,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AddSplitPotPrizeLog {
+    pub event_id: EventId,
+    pub prize_index: u64,
+    pub amount: U128,
+    pub split: PrizeSplit,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JoinEventLog {
+    pub event_id: EventId,
+    pub account_id: AccountId,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct BanParticipantLog {
+    pub event_id: EventId,
+    pub account_id: AccountId,
+    pub was_participant: bool,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RaffleEventPrizesLog {
+    pub event_id: EventId,
+    pub winners: Vec<PrizeWinnerLog>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PrizeWinnerLog {
+    pub prize_index: u64,
+    pub slot_index: u64,
+    pub account_id: AccountId,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct FailEventLog {
+    pub event_id: EventId,
+    pub participants_amount: u64,
+    pub min_participants: u64,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ClaimPrizeLog {
+    pub event_id: EventId,
+    pub prize_index: u64,
+    pub slot_index: u64,
+    pub account_id: AccountId,
+    pub amount: U128,
+}
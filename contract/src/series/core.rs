@@ -0,0 +1,126 @@
+use crate::errors::RafflerError;
+use crate::event::types::EventId;
+use crate::utils::{current_time_ms, get_storage_cost};
+use crate::{Contract, ContractExt};
+use near_sdk::{env, near_bindgen};
+
+use super::types::{Series, SeriesId};
+
+#[near_bindgen]
+impl Contract {
+    pub(super) fn internal_get_series(&self, id: &SeriesId) -> Series {
+        self.series
+            .get(id)
+            .unwrap_or_else(|| RafflerError::SeriesNotFound { series_id: *id }.panic())
+    }
+
+    // creates the next event of the series once the previous one is raffled or has ended,
+    // returns nothing if it isn't the time yet or all events are already created
+    pub(super) fn internal_advance_series(&mut self, series_id: &SeriesId) -> Option<EventId> {
+        let mut series = self.internal_get_series(series_id);
+
+        if !self.is_series_event_due(&series) {
+            return None;
+        }
+
+        let storage_before = env::storage_usage();
+
+        let event_id = self.internal_create_series_event(&mut series);
+
+        let storage_used = env::storage_usage() - storage_before;
+
+        // the whole storage of event is charged to the owner, either reserved or now
+        self.internal_add_event_storage_usage(&event_id, storage_used);
+
+        // storage of events was reserved on creation of series, so only the excess is charged
+        if storage_used > series.storage_per_event {
+            self.internal_charge_storage(&series.owner_id, storage_used - series.storage_per_event);
+        }
+
+        self.series.insert(series_id, &series);
+
+        Some(event_id)
+    }
+
+    // called on raffle of the event, which shouldn't fail because of the series,
+    // so the next event is skipped if it can't be paid & left for 'advance_series'
+    pub(crate) fn internal_try_advance_series(&mut self, series_id: &SeriesId) -> Option<EventId> {
+        let series = self.internal_get_series(series_id);
+
+        if !self.is_series_event_due(&series) {
+            return None;
+        }
+
+        // storage of events differs only by few bytes, so the excess over the reserved
+        // storage is covered if the owner could pay for the whole event once more
+        let available_storage = self
+            .storage_accounts
+            .get(&series.owner_id)
+            .map_or(0, |account_storage| {
+                account_storage.total - account_storage.used
+            });
+
+        if available_storage < get_storage_cost(series.storage_per_event) {
+            return None;
+        }
+
+        self.internal_advance_series(series_id)
+    }
+
+    fn is_series_event_due(&self, series: &Series) -> bool {
+        if series.events.len() >= series.schedule.occurrences {
+            return false;
+        }
+
+        let previous_event_id = match series.events.get(series.events.len().saturating_sub(1)) {
+            Some(id) => id,
+            None => return true,
+        };
+
+        match self.internal_find_event(&previous_event_id) {
+            Some(e) => e.raffled || e.ended_at <= current_time_ms(),
+            // archived
+            None => true,
+        }
+    }
+
+    // creates the event by the schedule with prizes paid from the budget of series
+    pub(super) fn internal_create_series_event(&mut self, series: &mut Series) -> EventId {
+        let scheduled_start_time =
+            series.schedule.start_time + series.events.len() * series.schedule.interval;
+
+        // event starts right away if it's created later than scheduled
+        let start_time = scheduled_start_time.max(current_time_ms() + 1);
+
+        let event_id = self.internal_create_event(
+            &series.owner_id,
+            series.title.clone(),
+            Some(start_time),
+            Some(start_time + series.schedule.duration),
+            series.metadata.clone(),
+        );
+
+        let mut event = self.internal_get_event(&event_id);
+
+        event.series_id = Some(series.id);
+        event.ticket_price = series.ticket_price;
+        // fees were deposited along with prizes on creation of series
+        event.fees = series.fees.clone();
+
+        self.internal_save_event(event);
+
+        for amount in series.prizes.iter() {
+            let event = self.internal_get_event(&event_id);
+
+            series.prize_budget -= self.internal_push_near_prize(event, *amount, 1);
+        }
+
+        let event = self.internal_get_event(&event_id);
+
+        self.internal_set_event_visible(event);
+
+        series.events.push(&event_id);
+
+        event_id
+    }
+}
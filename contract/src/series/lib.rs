@@ -0,0 +1,446 @@
+use near_sdk::collections::Vector;
+use near_sdk::json_types::U128;
+use near_sdk::{env, Balance};
+
+use super::types::*;
+use crate::errors::RafflerError;
+use crate::event::types::{EventId, EventMetadata, MAX_WINNERS_PER_EVENT};
+use crate::logs::types::{AddSeriesLog, RafflerLogEvent};
+use crate::utils::*;
+use crate::*;
+
+use witgen::witgen;
+
+#[near_bindgen]
+impl Contract {
+    #[witgen]
+    #[payable]
+    pub fn create_series(
+        &mut self,
+        title: String,
+        metadata: Option<EventMetadata>,
+        ticket_price: Option<U128>,
+        prizes: Vec<U128>,
+        schedule: SeriesSchedule,
+    ) -> SeriesId {
+        self.assert_not_paused();
+
+        self.assert_not_blocked(&env::predecessor_account_id());
+
+        self.assert_creator_allowed(&env::predecessor_account_id());
+
+        assert_at_least_one_yocto();
+
+        assert_condition(
+            schedule.occurrences >= 1
+                && schedule.duration > 0
+                && schedule.interval >= schedule.duration,
+            RafflerError::InvalidSeriesSchedule,
+        );
+
+        assert_condition(
+            current_time_ms() < schedule.start_time,
+            RafflerError::StartTimeInPast,
+        );
+
+        assert_condition(!prizes.is_empty(), RafflerError::SeriesWithoutPrizes);
+
+        assert_condition(
+            prizes.len() as u64 <= self.config.max_prizes_per_event,
+            RafflerError::TooManyPrizes {
+                max: self.config.max_prizes_per_event,
+            },
+        );
+
+        // every prize of series event has a single winner
+        assert_condition(
+            prizes.len() as u64 <= MAX_WINNERS_PER_EVENT,
+            RafflerError::TooManyWinners {
+                max: MAX_WINNERS_PER_EVENT,
+            },
+        );
+
+        for amount in prizes.iter() {
+            assert_condition(
+                amount.0 >= self.config.min_near_prize_amount.0,
+                RafflerError::PrizeAmountTooSmall {
+                    min: self.config.min_near_prize_amount.0,
+                },
+            );
+        }
+
+        // later changes of fees don't affect already created series
+        let fees = self.config.fees.clone();
+
+        // prizes of all events + platform fees are deposited at once
+        let prize_budget = prizes
+            .iter()
+            .map(|amount| amount.0 + get_fee_amount(amount.0, fees.prize_fee_bps))
+            .sum::<Balance>()
+            * Balance::from(schedule.occurrences);
+
+        assert_condition(
+            env::attached_deposit() >= prize_budget,
+            RafflerError::NotEnoughDeposit {
+                required: prize_budget,
+            },
+        );
+
+        let series_id = self.next_series_id;
+        let owner_id = env::predecessor_account_id();
+
+        let mut prefix = Vec::with_capacity(34);
+
+        // short version of "series events"
+        prefix.extend(b"se");
+        prefix.extend(env::sha256(&series_id.to_be_bytes()));
+
+        let mut series = Series {
+            id: series_id,
+            owner_id: owner_id.clone(),
+            title,
+            metadata: metadata.unwrap_or_default(),
+            ticket_price: ticket_price.map_or(0, |price| price.0),
+            prizes,
+            schedule,
+            events: Vector::new(prefix),
+            fees,
+            prize_budget,
+            storage_per_event: 0,
+        };
+
+        let storage_before = env::storage_usage();
+
+        self.next_series_id += 1;
+
+        // the first event is created right away, so params of series are validated as of event
+        let event_id = self.internal_create_series_event(&mut series);
+
+        series.storage_per_event = env::storage_usage() - storage_before;
+
+        self.internal_add_event_storage_usage(&event_id, series.storage_per_event);
+
+        let remaining_events = series.schedule.occurrences - 1;
+        let storage_per_event = series.storage_per_event;
+
+        RafflerLogEvent::AddSeries(vec![AddSeriesLog {
+            series_id,
+            owner_id: owner_id.clone(),
+            title: series.title.clone(),
+            occurrences: series.schedule.occurrences,
+        }])
+        .emit();
+
+        self.series.insert(&series_id, &series);
+
+        self.internal_pay_for_storage(
+            &owner_id,
+            env::attached_deposit() - prize_budget,
+            storage_before,
+        );
+
+        // storage of the rest of events is reserved upfront, so they could be created by anyone
+        self.internal_charge_storage(&owner_id, storage_per_event * remaining_events);
+
+        series_id
+    }
+
+    /// Creates the next event of the series once the previous one has ended, can be called by anyone
+    #[witgen]
+    pub fn advance_series(&mut self, series_id: SeriesId) -> EventId {
+        self.assert_not_paused();
+
+        let series = self.internal_get_series(&series_id);
+
+        assert_condition(
+            series.events.len() < series.schedule.occurrences,
+            RafflerError::SeriesCompleted { series_id },
+        );
+
+        self.internal_advance_series(&series_id)
+            .unwrap_or_else(|| RafflerError::SeriesEventNotDue { series_id }.panic())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::event::types::EventStatus;
+    use crate::series::types::SeriesSchedule;
+    use crate::utils::get_storage_cost;
+    use crate::Contract;
+
+    use near_sdk::json_types::U128;
+    use near_sdk::test_utils::VMContextBuilder;
+    use near_sdk::{testing_env, MockedBlockchain};
+
+    const START_TIME: u64 = 2_000_000;
+    const ACTIVE_TIME: u64 = 3_000_000;
+    const END_TIME: u64 = 4_000_000;
+    const RAFFLE_TIME: u64 = 5_000_000;
+    const LATE_TIME: u64 = 6_000_000;
+    const NEXT_END_TIME: u64 = 7_000_000;
+
+    const PRIZE_AMOUNT: u128 = 1_000_000_000_000_000_000_000_000;
+
+    fn get_schedule(occurrences: u64) -> SeriesSchedule {
+        SeriesSchedule {
+            start_time: START_TIME,
+            duration: END_TIME - START_TIME,
+            interval: RAFFLE_TIME - START_TIME,
+            occurrences,
+        }
+    }
+
+    #[test]
+    fn pass_create_series() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(5_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let series_id = contract.create_series(
+            String::from("weekly"),
+            None,
+            None,
+            vec![U128(PRIZE_AMOUNT)],
+            get_schedule(3),
+        );
+
+        let series = contract.get_series(series_id).unwrap();
+
+        // prizes of the first event are already taken from the budget
+        assert_eq!(series.prize_budget, U128(2 * PRIZE_AMOUNT));
+        assert_eq!(series.events.len(), 1);
+
+        let event = series.events.get(0).unwrap();
+
+        assert_eq!(event.status, EventStatus::Visible);
+        assert_eq!(event.series_id, Some(series_id));
+        assert_eq!(event.started_at, START_TIME);
+        assert_eq!(event.ended_at, END_TIME);
+        assert_eq!(event.prizes.len(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "You should attach at least 3000000000000000000000000 yoctoNear")]
+    fn panic_on_create_series_without_prizes_budget() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        contract.create_series(
+            String::from("weekly"),
+            None,
+            None,
+            vec![U128(PRIZE_AMOUNT)],
+            get_schedule(3),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "'interval' not less than 'duration'")]
+    fn panic_on_create_series_with_overlapping_events() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(5_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        let mut schedule = get_schedule(3);
+        schedule.interval = schedule.duration - 1;
+
+        testing_env!(context.build());
+        contract.create_series(
+            String::from("weekly"),
+            None,
+            None,
+            vec![U128(PRIZE_AMOUNT)],
+            schedule,
+        );
+    }
+
+    #[test]
+    fn pass_advance_series_on_raffle() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(5_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let series_id = contract.create_series(
+            String::from("weekly"),
+            None,
+            None,
+            vec![U128(PRIZE_AMOUNT)],
+            get_schedule(2),
+        );
+
+        let event_id = contract.get_series(series_id).unwrap().events[0].id;
+
+        testing_env!(context
+            .predecessor_account_id("den".try_into().unwrap())
+            .block_timestamp(ACTIVE_TIME * 1_000_000)
+            .build());
+        contract.join_event(event_id);
+
+        testing_env!(context
+            .predecessor_account_id("owner".try_into().unwrap())
+            .block_timestamp(END_TIME * 1_000_000)
+            .build());
+        contract.raffle_event_prizes(event_id);
+
+        let series = contract.get_series(series_id).unwrap();
+
+        assert_eq!(series.prize_budget, U128(0));
+        assert_eq!(series.events.len(), 2);
+
+        let next_event = series.events.get(1).unwrap();
+
+        assert_eq!(next_event.status, EventStatus::Visible);
+        assert_eq!(next_event.started_at, RAFFLE_TIME);
+        assert_eq!(next_event.ended_at, NEXT_END_TIME);
+    }
+
+    #[test]
+    fn pass_skip_advancing_series_on_raffle_without_storage() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(5_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let series_id = contract.create_series(
+            String::from("weekly"),
+            None,
+            None,
+            vec![U128(PRIZE_AMOUNT)],
+            get_schedule(2),
+        );
+
+        let event_id = contract.get_series(series_id).unwrap().events[0].id;
+
+        testing_env!(context
+            .predecessor_account_id("den".try_into().unwrap())
+            .block_timestamp(ACTIVE_TIME * 1_000_000)
+            .build());
+        contract.join_event(event_id);
+
+        testing_env!(context
+            .predecessor_account_id("owner".try_into().unwrap())
+            .attached_deposit(1)
+            .build());
+        contract.storage_withdraw(None);
+
+        // covers records of winners, but not one more event
+        let event_storage_cost =
+            get_storage_cost(contract.internal_get_series(&series_id).storage_per_event);
+
+        testing_env!(context
+            .attached_deposit(event_storage_cost / 2)
+            .block_timestamp(END_TIME * 1_000_000)
+            .build());
+        contract.raffle_event_prizes(event_id);
+
+        assert_eq!(contract.get_series(series_id).unwrap().events.len(), 1);
+
+        testing_env!(context
+            .predecessor_account_id("alice".try_into().unwrap())
+            .attached_deposit(0)
+            .build());
+        contract.advance_series(series_id);
+
+        assert_eq!(contract.get_series(series_id).unwrap().events.len(), 2);
+    }
+
+    #[test]
+    fn pass_advance_series_after_end_of_late_event() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(5_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let series_id = contract.create_series(
+            String::from("weekly"),
+            None,
+            None,
+            vec![U128(PRIZE_AMOUNT)],
+            get_schedule(2),
+        );
+
+        // anyone is able to advance the series, even if the owner hasn't raffled prizes
+        testing_env!(context
+            .predecessor_account_id("den".try_into().unwrap())
+            .attached_deposit(0)
+            .block_timestamp(LATE_TIME * 1_000_000)
+            .build());
+        let event_id = contract.advance_series(series_id);
+
+        let event = contract.get_event(event_id).unwrap();
+
+        // event starts right away, since it's already late
+        assert_eq!(event.series_id, Some(series_id));
+        assert_eq!(event.started_at, LATE_TIME + 1);
+        assert_eq!(event.ended_at, LATE_TIME + 1 + END_TIME - START_TIME);
+    }
+
+    #[test]
+    #[should_panic(expected = "Previous event of the series hasn't ended yet")]
+    fn panic_on_advance_series_before_end() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(5_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let series_id = contract.create_series(
+            String::from("weekly"),
+            None,
+            None,
+            vec![U128(PRIZE_AMOUNT)],
+            get_schedule(2),
+        );
+
+        testing_env!(context.block_timestamp(ACTIVE_TIME * 1_000_000).build());
+        contract.advance_series(series_id);
+    }
+
+    #[test]
+    #[should_panic(expected = "All events of the series are already created")]
+    fn panic_on_advance_completed_series() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(5_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let series_id = contract.create_series(
+            String::from("weekly"),
+            None,
+            None,
+            vec![U128(PRIZE_AMOUNT)],
+            get_schedule(1),
+        );
+
+        testing_env!(context.block_timestamp(RAFFLE_TIME * 1_000_000).build());
+        contract.advance_series(series_id);
+    }
+}
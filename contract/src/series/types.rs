@@ -0,0 +1,57 @@
+use crate::admin::types::Fees;
+use crate::event::types::{EventId, EventMetadata, JsonEvent};
+use crate::types::TimestampMs;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::Vector;
+use near_sdk::json_types::U128;
+use near_sdk::{
+    serde::{Deserialize, Serialize},
+    AccountId, Balance, StorageUsage,
+};
+use witgen::witgen;
+
+#[witgen]
+pub type SeriesId = u64;
+
+// events of the series are created one by one by the schedule, the next one is created
+// once the previous one is raffled (if the owner's storage balance covers it) or by
+// 'advance_series' after its end
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Series {
+    pub id: SeriesId,
+    pub owner_id: AccountId,
+    pub title: String,
+    pub metadata: EventMetadata,
+    pub ticket_price: Balance,
+    pub prizes: Vec<U128>, // NEAR prizes of every event
+    pub schedule: SeriesSchedule,
+    pub events: Vector<EventId>,
+    pub fees: Fees, // snapshot of fees, which were deposited along with prizes
+    pub prize_budget: Balance, // prizes & fees of events which aren't created yet
+    pub storage_per_event: StorageUsage, // reserved for every event which isn't created yet
+}
+
+#[witgen]
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct SeriesSchedule {
+    pub start_time: TimestampMs, // start of the first event
+    pub duration: u64,           // in milliseconds
+    pub interval: u64,           // between starts of events, in milliseconds
+    pub occurrences: u64,        // amount of events in the series
+}
+
+#[witgen]
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonSeries {
+    pub id: SeriesId,
+    pub owner_id: AccountId,
+    pub title: String,
+    pub metadata: EventMetadata,
+    pub ticket_price: U128,
+    pub prizes: Vec<U128>,
+    pub schedule: SeriesSchedule,
+    pub prize_budget: U128,
+    pub events: Vec<JsonEvent>,
+}
@@ -0,0 +1,27 @@
+use crate::*;
+use near_sdk::json_types::U128;
+
+use super::types::{JsonSeries, SeriesId};
+use witgen::witgen;
+
+#[near_bindgen]
+impl Contract {
+    #[witgen]
+    pub fn get_series(&self, series_id: SeriesId) -> Option<JsonSeries> {
+        self.series.get(&series_id).map(|series| JsonSeries {
+            id: series.id,
+            owner_id: series.owner_id.clone(),
+            title: series.title.clone(),
+            metadata: series.metadata.clone(),
+            ticket_price: U128(series.ticket_price),
+            prizes: series.prizes.clone(),
+            events: series
+                .events
+                .iter()
+                .filter_map(|event_id| self.internal_find_event_json(&event_id))
+                .collect(),
+            schedule: series.schedule,
+            prize_budget: U128(series.prize_budget),
+        })
+    }
+}
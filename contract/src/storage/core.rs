@@ -0,0 +1,99 @@
+use crate::errors::RafflerError;
+use crate::utils::{assert_condition, get_storage_cost};
+use crate::{Contract, ContractExt};
+use near_sdk::{env, near_bindgen, AccountId, Balance, StorageUsage};
+
+use super::types::AccountStorage;
+
+#[near_bindgen]
+impl Contract {
+    // measures how much storage takes the record of account with the longest possible id
+    pub(crate) fn measure_account_storage_usage(&mut self) {
+        let storage_before = env::storage_usage();
+
+        let tmp_account_id: AccountId = "a".repeat(64).parse().unwrap();
+
+        self.storage_accounts
+            .insert(&tmp_account_id, &AccountStorage { total: 0, used: 0 });
+
+        self.account_storage_usage = env::storage_usage() - storage_before;
+
+        self.storage_accounts.remove(&tmp_account_id);
+    }
+
+    pub(crate) fn internal_storage_balance_min(&self) -> Balance {
+        get_storage_cost(self.account_storage_usage)
+    }
+
+    pub(crate) fn internal_deposit_storage(&mut self, account_id: &AccountId, amount: Balance) {
+        let mut account_storage =
+            self.storage_accounts
+                .get(account_id)
+                .unwrap_or_else(|| AccountStorage {
+                    total: 0,
+                    // account pays for its own record
+                    used: self.internal_storage_balance_min(),
+                });
+
+        account_storage.total += amount;
+
+        self.storage_accounts.insert(account_id, &account_storage);
+    }
+
+    // credits the deposit to the storage balance of account & charges it
+    // for the storage which was occupied since 'storage_before'
+    pub(crate) fn internal_pay_for_storage(
+        &mut self,
+        account_id: &AccountId,
+        deposit: Balance,
+        storage_before: StorageUsage,
+    ) {
+        let storage_used = env::storage_usage().saturating_sub(storage_before);
+
+        // record of a new account is charged on registration
+        self.internal_deposit_storage(account_id, deposit);
+
+        self.internal_charge_storage(account_id, storage_used);
+    }
+
+    pub(crate) fn internal_charge_storage(
+        &mut self,
+        account_id: &AccountId,
+        storage_used: StorageUsage,
+    ) {
+        let mut account_storage = self
+            .storage_accounts
+            .get(account_id)
+            .unwrap_or_else(|| RafflerError::AccountNotRegistered.panic());
+
+        account_storage.used += get_storage_cost(storage_used);
+
+        assert_condition(
+            account_storage.used <= account_storage.total,
+            RafflerError::NotEnoughStorageBalance {
+                required: account_storage.used.saturating_sub(account_storage.total),
+            },
+        );
+
+        self.storage_accounts.insert(account_id, &account_storage);
+    }
+
+    // makes released storage available for withdrawal, the record of account itself stays charged
+    pub(crate) fn internal_release_storage(
+        &mut self,
+        account_id: &AccountId,
+        storage_released: StorageUsage,
+    ) {
+        let mut account_storage = match self.storage_accounts.get(account_id) {
+            Some(a) => a,
+            None => return,
+        };
+
+        account_storage.used = account_storage
+            .used
+            .saturating_sub(get_storage_cost(storage_released))
+            .max(self.internal_storage_balance_min());
+
+        self.storage_accounts.insert(account_id, &account_storage);
+    }
+}
@@ -0,0 +1,308 @@
+use near_sdk::json_types::U128;
+use near_sdk::{env, AccountId, Promise};
+
+use super::types::StorageBalance;
+use crate::errors::RafflerError;
+use crate::utils::*;
+use crate::*;
+
+use witgen::witgen;
+
+// NEP-145 storage management, storage used by the account is charged against its balance
+#[near_bindgen]
+impl Contract {
+    #[witgen]
+    #[payable]
+    pub fn storage_deposit(
+        &mut self,
+        account_id: Option<AccountId>,
+        registration_only: Option<bool>,
+    ) -> StorageBalance {
+        self.assert_not_paused();
+
+        let account_id = account_id.unwrap_or_else(env::predecessor_account_id);
+        let amount = env::attached_deposit();
+
+        let is_registered = self.storage_accounts.contains_key(&account_id);
+
+        if registration_only.unwrap_or(false) {
+            // only minimal balance is taken, the rest is refunded
+            let min_balance = if is_registered {
+                0
+            } else {
+                self.internal_storage_balance_min()
+            };
+
+            assert_condition(
+                amount >= min_balance,
+                RafflerError::NotEnoughRegistrationDeposit {
+                    required: min_balance,
+                },
+            );
+
+            if !is_registered {
+                self.internal_deposit_storage(&account_id, min_balance);
+            }
+
+            let refund = amount - min_balance;
+
+            if refund > 0 {
+                Promise::new(env::predecessor_account_id()).transfer(refund);
+            }
+        } else {
+            assert_condition(
+                is_registered || amount >= self.internal_storage_balance_min(),
+                RafflerError::NotEnoughRegistrationDeposit {
+                    required: self.internal_storage_balance_min(),
+                },
+            );
+
+            self.internal_deposit_storage(&account_id, amount);
+        }
+
+        self.storage_balance_of(account_id).unwrap()
+    }
+
+    #[witgen]
+    #[payable]
+    pub fn storage_withdraw(&mut self, amount: Option<U128>) -> StorageBalance {
+        assert_exactly_one_yocto();
+
+        self.assert_not_paused();
+
+        let account_id = env::predecessor_account_id();
+
+        let mut account_storage = self
+            .storage_accounts
+            .get(&account_id)
+            .unwrap_or_else(|| RafflerError::AccountNotRegistered.panic());
+
+        let available = account_storage.total - account_storage.used;
+        let amount = amount.map_or(available, |a| a.0);
+
+        assert_condition(
+            amount <= available,
+            RafflerError::NotEnoughAvailableStorage { available },
+        );
+
+        account_storage.total -= amount;
+
+        self.storage_accounts.insert(&account_id, &account_storage);
+
+        if amount > 0 {
+            Promise::new(account_id.clone()).transfer(amount);
+        }
+
+        self.storage_balance_of(account_id).unwrap()
+    }
+
+    #[witgen]
+    #[payable]
+    pub fn storage_unregister(&mut self, force: Option<bool>) -> bool {
+        assert_exactly_one_yocto();
+
+        self.assert_not_paused();
+
+        // storage occupied by events & participations can't be released on behalf of the account
+        assert_condition(
+            !force.unwrap_or(false),
+            RafflerError::ForceUnregisterUnsupported,
+        );
+
+        let account_id = env::predecessor_account_id();
+
+        let account_storage = match self.storage_accounts.get(&account_id) {
+            Some(a) => a,
+            None => return false,
+        };
+
+        assert_condition(
+            account_storage.used <= self.internal_storage_balance_min(),
+            RafflerError::AccountOccupiesStorage,
+        );
+
+        self.storage_accounts.remove(&account_id);
+
+        Promise::new(account_id).transfer(account_storage.total);
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Contract;
+
+    use near_sdk::json_types::U128;
+    use near_sdk::test_utils::VMContextBuilder;
+    use near_sdk::{testing_env, MockedBlockchain};
+
+    const START_TIME: u64 = 2_000_000;
+    const ACTIVE_TIME: u64 = 3_000_000;
+    const END_TIME: u64 = 4_000_000;
+
+    #[test]
+    fn pass_storage_deposit_and_withdraw() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.predecessor_account_id("den".try_into().unwrap());
+
+        testing_env!(context.build());
+        assert!(contract.storage_balance_of("den".to_string()).is_none());
+
+        let min_balance = contract.storage_balance_bounds().min.0;
+
+        testing_env!(context.attached_deposit(min_balance + 1_000).build());
+        let balance = contract.storage_deposit(None, None);
+
+        assert_eq!(balance.total, U128(min_balance + 1_000));
+        assert_eq!(balance.available, U128(1_000));
+
+        testing_env!(context.attached_deposit(1).build());
+        let balance = contract.storage_withdraw(Some(U128(400)));
+
+        assert_eq!(balance.total, U128(min_balance + 600));
+        assert_eq!(balance.available, U128(600));
+
+        testing_env!(context.build());
+        assert!(contract.storage_unregister(None));
+        assert!(contract.storage_balance_of("den".to_string()).is_none());
+    }
+
+    #[test]
+    fn pass_storage_deposit_registration_only() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.predecessor_account_id("den".try_into().unwrap());
+
+        let min_balance = contract.storage_balance_bounds().min.0;
+
+        testing_env!(context
+            .attached_deposit(1_000_000_000_000_000_000_000_000)
+            .build());
+        let balance = contract.storage_deposit(Some("den2".to_string()), Some(true));
+
+        assert_eq!(balance.total, U128(min_balance));
+        assert_eq!(balance.available, U128(0));
+        assert!(contract.storage_balance_of("den".to_string()).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Must attach at least")]
+    fn panic_on_storage_deposit_less_than_min() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        testing_env!(context
+            .predecessor_account_id("den".try_into().unwrap())
+            .attached_deposit(1)
+            .build());
+        contract.storage_deposit(None, None);
+    }
+
+    #[test]
+    fn pass_join_event_with_one_yocto_after_storage_deposit() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.set_event_visible(event_id.clone());
+
+        testing_env!(context
+            .attached_deposit(100_000_000_000_000_000_000_000)
+            .predecessor_account_id("den".try_into().unwrap())
+            .build());
+        contract.storage_deposit(None, None);
+
+        testing_env!(context
+            .attached_deposit(1)
+            .block_timestamp(ACTIVE_TIME * 1_000_000)
+            .build());
+        contract.join_event(event_id.clone());
+
+        let balance = contract.storage_balance_of("den".to_string()).unwrap();
+        assert!(balance.available.0 < 100_000_000_000_000_000_000_000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Not enough storage balance")]
+    fn panic_on_join_event_with_one_yocto_without_storage_deposit() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.add_near_prize(
+            event_id.clone(),
+            U128(1_000_000_000_000_000_000_000_000),
+            None,
+        );
+
+        testing_env!(context.build());
+        contract.set_event_visible(event_id.clone());
+
+        testing_env!(context
+            .attached_deposit(1)
+            .block_timestamp(ACTIVE_TIME * 1_000_000)
+            .predecessor_account_id("den".try_into().unwrap())
+            .build());
+        contract.join_event(event_id.clone());
+    }
+
+    #[test]
+    #[should_panic(expected = "Couldn't unregister the account which still occupies storage")]
+    fn panic_on_storage_unregister_of_event_owner() {
+        let mut contract = Contract::new();
+
+        let mut context = VMContextBuilder::new();
+
+        context.attached_deposit(2_000_000_000_000_000_000_000_000);
+        context.predecessor_account_id("owner".try_into().unwrap());
+
+        testing_env!(context.build());
+        contract.add_event(
+            String::from("title"),
+            Some(START_TIME),
+            Some(END_TIME),
+            None,
+        );
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.storage_unregister(None);
+    }
+}
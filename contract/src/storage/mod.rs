@@ -0,0 +1,6 @@
+// core modules (private)
+mod core;
+// export modules
+pub mod lib;
+pub mod types; // only storage management related types
+pub mod views;
@@ -0,0 +1,27 @@
+use crate::*;
+use near_sdk::json_types::U128;
+use near_sdk::AccountId;
+
+use super::types::{StorageBalance, StorageBalanceBounds};
+use witgen::witgen;
+
+#[near_bindgen]
+impl Contract {
+    #[witgen]
+    pub fn storage_balance_of(&self, account_id: AccountId) -> Option<StorageBalance> {
+        self.storage_accounts
+            .get(&account_id)
+            .map(|account_storage| StorageBalance {
+                total: U128(account_storage.total),
+                available: U128(account_storage.total - account_storage.used),
+            })
+    }
+
+    #[witgen]
+    pub fn storage_balance_bounds(&self) -> StorageBalanceBounds {
+        StorageBalanceBounds {
+            min: U128(self.internal_storage_balance_min()),
+            max: None,
+        }
+    }
+}
@@ -7,14 +7,27 @@ use witgen::witgen;
 // constants
 pub const DEFAULT_PAGINATION_LIMIT: u64 = 5;
 pub const MAX_PAGINATION_LIMIT: u64 = 5;
+// fees & shares of split pots are given in basis points, 10_000 of them is 100%
+pub const BASIS_POINTS: u32 = 10_000;
 
 #[witgen]
 pub type TimestampMs = u64;
 
+// key based pagination, the page starts right after 'from_key', so its cost doesn't depend
+// on how far the page is & entries which are added or removed meanwhile don't shift it
 #[witgen]
 #[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
 #[serde(crate = "near_sdk::serde")]
-pub struct Pagination {
-    pub page: u64,
+pub struct CursorPagination<K> {
+    pub from_key: Option<K>, // absent for the first page
     pub limit: u64,
 }
+
+#[witgen]
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Page<T, K> {
+    pub items: Vec<T>,
+    pub next_key: Option<K>, // 'from_key' of the next page, absent for the last one
+    pub total: u64,
+}
@@ -0,0 +1,443 @@
+use near_sdk::borsh::BorshDeserialize;
+use near_sdk::collections::{LookupMap, TreeMap};
+use near_sdk::{env, Gas, Promise};
+
+use super::types::ContractV1;
+use crate::admin::types::Config;
+use crate::errors::RafflerError;
+use crate::utils::*;
+use crate::*;
+
+// gas which is left for the 'upgrade' call itself, the rest is attached to 'migrate'
+const GAS_FOR_UPGRADE: Gas = Gas(10_000_000_000_000);
+
+const STATE_KEY: &[u8] = b"STATE";
+
+#[near_bindgen]
+impl Contract {
+    /// Deploys the new code passed as raw input (not JSON) and migrates the state
+    pub fn upgrade(&self) -> Promise {
+        self.assert_admin();
+
+        let code = env::input().unwrap_or_default();
+
+        assert_condition(!code.is_empty(), RafflerError::MissingContractCode);
+
+        Promise::new(env::current_account_id())
+            .deploy_contract(code)
+            .function_call(
+                "migrate".to_string(),
+                Vec::new(),
+                0,
+                env::prepaid_gas() - env::used_gas() - GAS_FOR_UPGRADE,
+            )
+    }
+
+    #[private]
+    #[init(ignore_state)]
+    pub fn migrate() -> Self {
+        let state = env::storage_read(STATE_KEY)
+            .unwrap_or_else(|| RafflerError::ContractNotInitialized.panic());
+
+        // state is already in the current layout, nothing to migrate
+        if let Ok(contract) = Contract::try_from_slice(&state) {
+            return contract;
+        }
+
+        let old_contract = ContractV1::try_from_slice(&state)
+            .unwrap_or_else(|_| RafflerError::InvalidContractState.panic());
+
+        // old events stay under the legacy prefix and are upgraded lazily once touched
+        let mut contract = Self {
+            admin_id: env::current_account_id(),
+            paused: false,
+            config: Config::default(),
+            blocked_accounts: TreeMap::new(to_storage_key("ba")),
+            blocked_subaccounts: TreeMap::new(to_storage_key("bs")),
+            verified_creators: TreeMap::new(to_storage_key("vc")),
+            accrued_fees: 0,
+            next_event_id: old_contract.next_event_id,
+            events: LookupMap::new(to_storage_key("ve")),
+            archived_events: LookupMap::new(to_storage_key("ae")),
+            visible_events_by_start: TreeMap::new(to_storage_key("vbs")),
+            visible_events_by_end: TreeMap::new(to_storage_key("vbe")),
+            // legacy entries are moved to the ordered collections along with their events,
+            // except of participants which are read from the legacy mapping
+            events_by_owner: LookupMap::new(to_storage_key("to")),
+            events_by_participant: LookupMap::new(to_storage_key("tp")),
+            unclaimed_prizes_by_account: LookupMap::new(to_storage_key("tu")),
+            next_series_id: 1,
+            series: LookupMap::new(to_storage_key("s")),
+            storage_accounts: LookupMap::new(to_storage_key("sa")),
+            account_storage_usage: 0,
+            join_storage_usage: 0,
+        };
+
+        contract.measure_account_storage_usage();
+        contract.measure_join_storage_usage();
+
+        contract
+    }
+
+    /// Moves up to 'limit' legacy events starting from the given id into the current layout,
+    /// so the visible ones are listed, returns how many events were migrated
+    #[payable]
+    pub fn migrate_legacy_events(&mut self, from_event_id: EventId, limit: u64) -> u64 {
+        assert_exactly_one_yocto();
+
+        self.assert_admin();
+
+        let to_event_id = self.next_event_id.min(from_event_id.saturating_add(limit));
+
+        let mut migrated = 0;
+
+        for event_id in from_event_id..to_event_id {
+            // events touched after the upgrade were migrated already
+            if self.internal_is_legacy_event(&event_id) {
+                self.internal_get_event(&event_id);
+
+                migrated += 1;
+            }
+        }
+
+        migrated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::event::types::{EventId, EventPrize, EventPrizeV1, EventStatus, PrizeType};
+    use crate::types::CursorPagination;
+    use crate::upgrade::types::ContractV1;
+    use crate::utils::to_storage_key;
+    use crate::Contract;
+
+    use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+    use near_sdk::collections::{LookupMap, UnorderedSet, Vector};
+    use near_sdk::json_types::U128;
+    use near_sdk::test_utils::VMContextBuilder;
+    use near_sdk::{env, testing_env, AccountId, MockedBlockchain};
+
+    const START_TIME: u64 = 2_000_000;
+    const END_TIME: u64 = 4_000_000;
+    const CLAIM_TIME: u64 = 6_000_000;
+
+    // event & prize exactly as they were stored by the first version of the contract
+    #[derive(BorshDeserialize, BorshSerialize)]
+    struct FixtureEvent {
+        id: EventId,
+        owner_id: AccountId,
+        title: String,
+        is_visible: bool,
+        started_at: u64,
+        ended_at: u64,
+        raffled: bool,
+        participants: UnorderedSet<AccountId>,
+        prizes: Vector<FixturePrize>,
+    }
+
+    #[derive(BorshDeserialize, BorshSerialize)]
+    struct FixturePrize {
+        prize_type: PrizeType,
+        winner_account_id: Option<AccountId>,
+        claimed: bool,
+    }
+
+    // writes the state of v1 contract with one raffled event which prize was won by "den"
+    fn write_v1_state_fixture() {
+        let mut events: LookupMap<EventId, FixtureEvent> = LookupMap::new(to_storage_key("e"));
+        let mut events_by_owner = LookupMap::new(to_storage_key("eo"));
+        let mut events_by_participant = LookupMap::new(to_storage_key("ep"));
+        let mut unclaimed_prizes_by_account = LookupMap::new(to_storage_key("upa"));
+
+        let mut participants = UnorderedSet::new(b"epa-1".to_vec());
+        participants.insert(&"den".to_string());
+
+        let mut prizes = Vector::new(b"ep-1".to_vec());
+        prizes.push(&FixturePrize {
+            prize_type: PrizeType::NEAR {
+                amount: U128(1_000_000_000_000_000_000_000_000),
+            },
+            winner_account_id: Some("den".to_string()),
+            claimed: false,
+        });
+
+        events.insert(
+            &1,
+            &FixtureEvent {
+                id: 1,
+                owner_id: "owner".to_string(),
+                title: String::from("title"),
+                is_visible: true,
+                started_at: START_TIME,
+                ended_at: END_TIME,
+                raffled: true,
+                participants,
+                prizes,
+            },
+        );
+
+        let mut owner_events = UnorderedSet::new(b"oe-owner".to_vec());
+        owner_events.insert(&1);
+        events_by_owner.insert(&"owner".to_string(), &owner_events);
+
+        let mut participant_events = UnorderedSet::new(b"pe-den".to_vec());
+        participant_events.insert(&1);
+        events_by_participant.insert(&"den".to_string(), &participant_events);
+
+        let mut unclaimed_prizes = UnorderedSet::new(b"up-den".to_vec());
+        unclaimed_prizes.insert(&EventPrizeV1 {
+            event_id: 1,
+            prize_index: 0,
+        });
+        unclaimed_prizes_by_account.insert(&"den".to_string(), &unclaimed_prizes);
+
+        env::state_write(&ContractV1 {
+            next_event_id: 2,
+            events: LookupMap::new(to_storage_key("e")),
+            events_by_owner,
+            events_by_participant,
+            unclaimed_prizes_by_account,
+        });
+    }
+
+    #[test]
+    fn pass_migrate_from_v1_state() {
+        let mut context = VMContextBuilder::new();
+
+        testing_env!(context
+            .current_account_id("raffler".try_into().unwrap())
+            .predecessor_account_id("raffler".try_into().unwrap())
+            .block_timestamp(CLAIM_TIME * 1_000_000)
+            .build());
+
+        write_v1_state_fixture();
+
+        let mut contract = Contract::migrate();
+
+        assert_eq!(contract.get_admin(), "raffler".to_string());
+        assert_eq!(contract.is_paused(), false);
+
+        let event = contract.get_event(1).unwrap();
+
+        assert_eq!(event.title, String::from("title"));
+        assert_eq!(event.owner_id, "owner".to_string());
+        assert_eq!(event.pending_owner_id, None);
+        assert_eq!(event.status, EventStatus::Claiming);
+        assert_eq!(event.participants_amount, 1);
+        assert_eq!(event.prizes.len(), 1);
+        assert_eq!(event.prizes[0].winners[0].account_id, "den".to_string());
+        assert_eq!(event.prizes[0].winners[0].claimed, false);
+
+        // mappings by account are moved along with the event
+        testing_env!(context.attached_deposit(1).build());
+        assert_eq!(contract.migrate_legacy_events(1, 10), 1);
+
+        let events = contract.get_owner_events("owner".to_string(), None);
+        assert_eq!(events.items.len(), 1);
+
+        let events = contract.get_participant_events("den".to_string(), None);
+        assert_eq!(events.items.len(), 1);
+
+        let prizes = contract.get_account_unclaimed_prizes("den".to_string(), None);
+        assert_eq!(prizes.items.len(), 1);
+    }
+
+    #[test]
+    fn pass_claim_prize_of_v1_event_after_migrate() {
+        let mut context = VMContextBuilder::new();
+
+        testing_env!(context
+            .current_account_id("raffler".try_into().unwrap())
+            .predecessor_account_id("raffler".try_into().unwrap())
+            .block_timestamp(CLAIM_TIME * 1_000_000)
+            .build());
+
+        write_v1_state_fixture();
+
+        let mut contract = Contract::migrate();
+
+        testing_env!(context
+            .predecessor_account_id("den".try_into().unwrap())
+            .build());
+        contract.claim_prize(EventPrize {
+            event_id: 1,
+            prize_index: 0,
+            slot_index: 0,
+        });
+
+        // event is moved out of the legacy storage and saved in the current layout
+        let legacy_events: LookupMap<EventId, FixtureEvent> = LookupMap::new(to_storage_key("e"));
+        assert!(!legacy_events.contains_key(&1));
+
+        let event = contract.get_event(1).unwrap();
+
+        assert_eq!(event.participants_amount, 1);
+        assert_eq!(event.prizes[0].winners[0].account_id, "den".to_string());
+        assert_eq!(event.prizes[0].winners[0].claimed, true);
+
+        let prizes = contract.get_account_unclaimed_prizes("den".to_string(), None);
+        assert_eq!(prizes.items.len(), 0);
+
+        // visible event is listed once it's migrated
+        assert_eq!(contract.get_events(None, None, None).items.len(), 1);
+    }
+
+    #[test]
+    fn pass_migrate_legacy_events_in_batches() {
+        let mut context = VMContextBuilder::new();
+
+        testing_env!(context
+            .current_account_id("raffler".try_into().unwrap())
+            .predecessor_account_id("raffler".try_into().unwrap())
+            .block_timestamp(CLAIM_TIME * 1_000_000)
+            .build());
+
+        write_v1_state_fixture();
+
+        let mut contract = Contract::migrate();
+
+        assert_eq!(contract.get_events(None, None, None).items.len(), 0);
+
+        testing_env!(context.attached_deposit(1).build());
+        assert_eq!(contract.migrate_legacy_events(1, 10), 1);
+
+        let events = contract.get_events(None, None, None);
+        assert_eq!(events.items.len(), 1);
+        assert_eq!(events.items[0].id, 1);
+
+        // already migrated events are skipped
+        assert_eq!(contract.migrate_legacy_events(1, 10), 0);
+    }
+
+    #[test]
+    fn pass_list_legacy_participant_events_along_with_current() {
+        let mut context = VMContextBuilder::new();
+
+        testing_env!(context
+            .current_account_id("raffler".try_into().unwrap())
+            .predecessor_account_id("raffler".try_into().unwrap())
+            .block_timestamp(CLAIM_TIME * 1_000_000)
+            .build());
+
+        write_v1_state_fixture();
+
+        let mut contract = Contract::migrate();
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.migrate_legacy_events(1, 10);
+
+        // participants of migrated event stay in the legacy mapping
+        let legacy_events_by_participant: LookupMap<AccountId, UnorderedSet<EventId>> =
+            LookupMap::new(to_storage_key("ep"));
+        assert!(legacy_events_by_participant
+            .get(&"den".to_string())
+            .unwrap()
+            .contains(&1));
+
+        testing_env!(context
+            .attached_deposit(2_000_000_000_000_000_000_000_000)
+            .predecessor_account_id("owner".try_into().unwrap())
+            .build());
+        let event_id = contract.add_event(
+            String::from("title"),
+            Some(CLAIM_TIME + 1_000),
+            Some(CLAIM_TIME + 2_000_000),
+            None,
+        );
+        contract.add_near_prize(event_id, U128(1_000_000_000_000_000_000_000_000), None);
+        contract.set_event_visible(event_id);
+
+        testing_env!(context
+            .predecessor_account_id("den".try_into().unwrap())
+            .block_timestamp((CLAIM_TIME + 1_000_000) * 1_000_000)
+            .build());
+        contract.join_event(event_id);
+
+        let events = contract.get_participant_events(
+            "den".to_string(),
+            Some(CursorPagination {
+                from_key: None,
+                limit: 1,
+            }),
+        );
+        assert_eq!(events.items[0].id, 1);
+        assert_eq!(events.next_key, Some(1));
+        assert_eq!(events.total, 2);
+
+        let events = contract.get_participant_events(
+            "den".to_string(),
+            Some(CursorPagination {
+                from_key: Some(1),
+                limit: 1,
+            }),
+        );
+        assert_eq!(events.items[0].id, event_id);
+        assert_eq!(events.next_key, None);
+
+        assert!(contract.is_user_joined_event("den".to_string(), 1));
+        assert!(contract.is_user_joined_event("den".to_string(), event_id));
+    }
+
+    #[test]
+    #[should_panic(expected = "This method can be accessed only by the contract admin")]
+    fn panic_on_no_admin_migrating_legacy_events() {
+        let mut context = VMContextBuilder::new();
+
+        testing_env!(context
+            .current_account_id("raffler".try_into().unwrap())
+            .predecessor_account_id("raffler".try_into().unwrap())
+            .build());
+
+        write_v1_state_fixture();
+
+        let mut contract = Contract::migrate();
+
+        testing_env!(context
+            .attached_deposit(1)
+            .predecessor_account_id("den".try_into().unwrap())
+            .build());
+        contract.migrate_legacy_events(1, 10);
+    }
+
+    #[test]
+    fn pass_migrate_current_state() {
+        let mut context = VMContextBuilder::new();
+
+        testing_env!(context
+            .predecessor_account_id("admin".try_into().unwrap())
+            .build());
+
+        let mut contract = Contract::new();
+
+        testing_env!(context
+            .attached_deposit(2_000_000_000_000_000_000_000_000)
+            .predecessor_account_id("owner".try_into().unwrap())
+            .build());
+        let event_id = contract.add_event(String::from("title"), None, None, None);
+
+        env::state_write(&contract);
+
+        let contract = Contract::migrate();
+
+        assert_eq!(contract.get_admin(), "admin".to_string());
+        assert!(contract.get_event(event_id).is_some());
+    }
+
+    #[test]
+    #[should_panic(expected = "This method can be accessed only by the contract admin")]
+    fn panic_on_no_admin_upgrading_contract() {
+        let mut context = VMContextBuilder::new();
+
+        testing_env!(context
+            .predecessor_account_id("admin".try_into().unwrap())
+            .build());
+
+        let contract = Contract::new();
+
+        testing_env!(context
+            .predecessor_account_id("den".try_into().unwrap())
+            .build());
+        contract.upgrade();
+    }
+}
@@ -0,0 +1,3 @@
+// export modules
+pub mod lib;
+pub mod types; // previous layouts of the contract state
@@ -0,0 +1,14 @@
+use crate::event::types::{EventId, EventPrizeV1, EventV1};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::{LookupMap, UnorderedSet};
+use near_sdk::AccountId;
+
+// layout of the contract state before admin, configuration and versioned events were introduced
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct ContractV1 {
+    pub next_event_id: EventId,
+    pub events: LookupMap<EventId, EventV1>,
+    pub events_by_owner: LookupMap<AccountId, UnorderedSet<EventId>>,
+    pub events_by_participant: LookupMap<AccountId, UnorderedSet<EventId>>,
+    pub unclaimed_prizes_by_account: LookupMap<AccountId, UnorderedSet<EventPrizeV1>>,
+}
@@ -1,11 +1,15 @@
-use crate::types::Pagination;
+use crate::errors::RafflerError;
+use crate::types::CursorPagination;
+use crate::types::Page;
 use crate::types::TimestampMs;
+use crate::types::BASIS_POINTS;
 use crate::types::DEFAULT_PAGINATION_LIMIT;
-use crate::types::MAX_PAGINATION_LIMIT;
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::collections::{TreeMap, Vector};
 use near_sdk::env;
 use near_sdk::Balance;
-use near_sdk::Promise;
 use near_sdk::StorageUsage;
+use std::ops::Bound;
 
 pub fn current_time_ms() -> TimestampMs {
     env::block_timestamp() / 1_000_000
@@ -19,38 +23,23 @@ pub fn get_storage_cost(storage_used: StorageUsage) -> Balance {
     env::storage_byte_cost() * Balance::from(storage_used)
 }
 
-pub fn refund_deposit(storage_used: StorageUsage) {
-    let storage_cost = get_storage_cost(storage_used);
-
-    let refund = env::attached_deposit() - storage_cost;
-
-    if refund > 1 {
-        Promise::new(env::predecessor_account_id()).transfer(refund);
-    }
-}
-
-pub fn assert_enough_attached_deposit(storage_used: StorageUsage) {
-    let storage_cost = get_storage_cost(storage_used);
-
-    assert_condition(
-        storage_cost <= env::attached_deposit(),
-        format!("Must attach {} yoctoNEAR to cover storage", storage_cost),
-    );
+pub fn get_fee_amount(amount: Balance, fee_bps: u32) -> Balance {
+    amount * Balance::from(fee_bps) / Balance::from(BASIS_POINTS)
 }
 
-pub fn assert_condition<S: AsRef<str>>(condition: bool, message: S) {
+pub fn assert_condition(condition: bool, error: RafflerError) {
     if condition {
         return;
     }
 
-    env::panic_str(message.as_ref());
+    error.panic();
 }
 
 /// Assert that at least 1 yoctoNEAR was attached.
 pub fn assert_at_least_one_yocto() {
     assert_condition(
         env::attached_deposit() >= 1,
-        "Requires attached deposit of at least 1 yoctoNEAR",
+        RafflerError::DepositLessThanOneYocto,
     );
 }
 
@@ -58,39 +47,191 @@ pub fn assert_at_least_one_yocto() {
 pub fn assert_exactly_one_yocto() {
     assert_condition(
         env::attached_deposit() == 1,
-        "Requires attached deposit of exactly 1 yoctoNEAR",
+        RafflerError::DepositNotOneYocto,
     );
 }
 
-pub fn unwrap_pagination(pagination: Option<Pagination>) -> (usize, usize) {
-    let actual_pagination = pagination.unwrap_or(Pagination {
-        page: 1,
+// returns the key after which the page starts & its actual limit
+pub fn unwrap_pagination<K>(
+    pagination: Option<CursorPagination<K>>,
+    max_limit: u64,
+) -> (Option<K>, usize) {
+    let actual_pagination = pagination.unwrap_or(CursorPagination {
+        from_key: None,
         limit: DEFAULT_PAGINATION_LIMIT,
     });
 
-    let actual_page = actual_pagination.page;
-    let actual_limit = if actual_pagination.limit > MAX_PAGINATION_LIMIT {
-        MAX_PAGINATION_LIMIT
-    } else {
-        actual_pagination.limit
+    // the cursor wouldn't move with empty pages
+    assert_condition(
+        actual_pagination.limit >= 1,
+        RafflerError::InvalidPaginationLimit,
+    );
+
+    (
+        actual_pagination.from_key,
+        actual_pagination.limit.min(max_limit) as usize,
+    )
+}
+
+// ranges of near-sdk maps are empty without the lower bound, so the first page starts
+// from the minimal key
+pub fn get_from_bound<K, V>(elements: &TreeMap<K, V>, from_key: Option<K>) -> Bound<K>
+where
+    K: Ord + Clone + BorshSerialize + BorshDeserialize,
+    V: BorshSerialize + BorshDeserialize,
+{
+    match from_key {
+        Some(key) => Bound::Excluded(key),
+        None => elements.min().map_or(Bound::Unbounded, Bound::Included),
+    }
+}
+
+// the map is ordered by keys, so only entries of the page are read
+pub fn get_page<K, V>(
+    elements: &TreeMap<K, V>,
+    pagination: Option<CursorPagination<K>>,
+    max_limit: u64,
+) -> Page<(K, V), K>
+where
+    K: Ord + Clone + BorshSerialize + BorshDeserialize,
+    V: BorshSerialize + BorshDeserialize,
+{
+    let (from_key, limit) = unwrap_pagination(pagination, max_limit);
+
+    let items: Vec<(K, V)> = elements
+        .range((get_from_bound(elements, from_key), Bound::Unbounded))
+        .take(limit)
+        .collect();
+
+    let next_key = match items.last() {
+        Some((key, _)) if items.len() == limit && elements.higher(key).is_some() => {
+            Some(key.clone())
+        }
+        _ => None,
+    };
+
+    Page {
+        items,
+        next_key,
+        total: elements.len(),
+    }
+}
+
+// pages of sets, which are kept as maps to unit
+pub fn get_key_page<K>(
+    elements: &TreeMap<K, ()>,
+    pagination: Option<CursorPagination<K>>,
+    max_limit: u64,
+) -> Page<K, K>
+where
+    K: Ord + Clone + BorshSerialize + BorshDeserialize,
+{
+    let page = get_page(elements, pagination, max_limit);
+
+    Page {
+        items: page.items.into_iter().map(|(key, _)| key).collect(),
+        next_key: page.next_key,
+        total: page.total,
+    }
+}
+
+// pages of the set which is split between the map & the list sorted in the same order
+pub fn get_merged_key_page<K>(
+    elements: &TreeMap<K, ()>,
+    sorted_elements: Vec<K>,
+    pagination: Option<CursorPagination<K>>,
+    max_limit: u64,
+) -> Page<K, K>
+where
+    K: Ord + Clone + BorshSerialize + BorshDeserialize,
+{
+    let (from_key, limit) = unwrap_pagination(pagination, max_limit);
+
+    let total = elements.len() + sorted_elements.len() as u64;
+
+    let from_bound = get_from_bound(elements, from_key.clone());
+
+    let mut sorted = sorted_elements
+        .into_iter()
+        .filter(|key| from_key.as_ref().is_none_or(|from_key| key > from_key))
+        .peekable();
+
+    let mut mapped = elements
+        .range((from_bound, Bound::Unbounded))
+        .map(|(key, _)| key)
+        .peekable();
+
+    let mut items: Vec<K> = Vec::new();
+
+    while items.len() < limit {
+        let from_sorted = match (sorted.peek(), mapped.peek()) {
+            (Some(sorted_key), Some(mapped_key)) => sorted_key < mapped_key,
+            (Some(_), None) => true,
+            (None, Some(_)) => false,
+            (None, None) => break,
+        };
+
+        let key = if from_sorted {
+            sorted.next()
+        } else {
+            mapped.next()
+        };
+
+        items.extend(key);
+    }
+
+    let next_key = match items.last() {
+        Some(key) if sorted.peek().is_some() || mapped.peek().is_some() => Some(key.clone()),
+        _ => None,
     };
 
-    let _skip = (actual_page - 1) * actual_limit;
-    let _take = actual_limit;
+    Page {
+        items,
+        next_key,
+        total,
+    }
+}
 
-    (_skip as usize, _take as usize)
+// the key of element is its index, so pages are stable only while elements are appended
+pub fn get_vector_page<T: BorshDeserialize>(
+    elements: &Vector<T>,
+    pagination: Option<CursorPagination<u64>>,
+    max_limit: u64,
+) -> Page<T, u64> {
+    let (from_key, limit) = unwrap_pagination(pagination, max_limit);
+
+    let total = elements.len();
+    let from_index = from_key.map_or(0, |index| index.saturating_add(1));
+    let end_index = total.min(from_index.saturating_add(limit as u64));
+
+    let items = (from_index..end_index)
+        .filter_map(|index| elements.get(index))
+        .collect();
+
+    Page {
+        items,
+        next_key: if end_index < total {
+            Some(end_index - 1)
+        } else {
+            None
+        },
+        total,
+    }
 }
 
-pub fn get_random_number(shift_amount: u32) -> u32 {
-    let mut seed = env::random_seed();
-    let seed_len = seed.len();
+// hashes the random seed of the block along with the salt, so every salt gets its own number
+pub fn get_random_number(salt: &[u64]) -> u64 {
+    let mut input = env::random_seed();
 
-    let mut arr: [u8; 4] = Default::default();
+    for value in salt {
+        input.extend(value.to_le_bytes());
+    }
+
+    let mut arr: [u8; 8] = Default::default();
 
-    seed.rotate_left(shift_amount as usize % seed_len);
-    arr.copy_from_slice(&seed[..4]);
+    arr.copy_from_slice(&env::sha256(&input)[..8]);
 
-    u32::from_le_bytes(arr)
+    u64::from_le_bytes(arr)
 }
 
 #[cfg(test)]
@@ -101,18 +242,18 @@ mod tests {
     use near_sdk::{testing_env, MockedBlockchain};
 
     #[test]
-    #[should_panic(expected = "panic!")]
+    #[should_panic(expected = "E101: Contract is paused")]
     fn panic_on_false_assert_condition() {
         let context = VMContextBuilder::new();
 
         testing_env!(context.build());
 
-        assert_condition(false, "panic!");
+        assert_condition(false, RafflerError::ContractPaused);
     }
 
     #[test]
     fn pass_assert_condition() {
-        assert_condition(true, "panic!");
+        assert_condition(true, RafflerError::ContractPaused);
     }
 
     #[test]
@@ -154,6 +295,19 @@ mod tests {
         assert_exactly_one_yocto();
     }
 
+    #[test]
+    fn pass_get_random_number_by_salt() {
+        let mut context = VMContextBuilder::new();
+
+        testing_env!(context.random_seed([7; 32]).build());
+
+        let number = get_random_number(&[0, 1]);
+
+        assert_eq!(get_random_number(&[0, 1]), number);
+        assert_ne!(get_random_number(&[1, 0]), number);
+        assert_ne!(get_random_number(&[0, 2]), number);
+    }
+
     #[test]
     fn pass_assert_at_least_one_yocto() {
         let mut context = VMContextBuilder::new();
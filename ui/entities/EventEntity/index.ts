@@ -7,11 +7,13 @@ import {
   Contract,
   EventId,
   EventStatus,
+  Fees,
   JsonEvent,
   Prize,
   SetEventTime,
   SetEventVisible,
   TimestampMs,
+  U128,
   u64,
 } from "types";
 
@@ -24,6 +26,10 @@ export class EventEntity implements JsonEvent {
   public prizes: Prize[];
   public participants_amount: u64;
   public owner_id: AccountId;
+  public verified: boolean;
+  public ticket_price: U128;
+  public min_participants: u64;
+  public fees: Fees;
 
   constructor(data: JsonEvent) {
     this.id = data.id;
@@ -34,6 +40,10 @@ export class EventEntity implements JsonEvent {
     this.prizes = data.prizes;
     this.participants_amount = data.participants_amount;
     this.owner_id = data.owner_id;
+    this.verified = data.verified;
+    this.ticket_price = data.ticket_price;
+    this.min_participants = data.min_participants;
+    this.fees = data.fees;
 
     makeAutoObservable(this, {}, { autoBind: true });
   }
@@ -401,14 +401,13 @@ const EventPage = (): JSX.Element => {
               <Box
                 sx={{ display: "flex", marginY: 1, justifyContent: "center" }}
               >
-                {event.prizes.map(
-                  ({ prize_type, winner_account_id }, index) => (
-                    <Item key={`prize-${index}`} sx={{ marginX: 0.5 }}>
-                      {winner_account_id} (
-                      {utils.format.formatNearAmount(prize_type.amount)}Ⓝ)
+                {event.prizes
+                  .flatMap(({ winners }) => winners)
+                  .map(({ account_id, amount }, index) => (
+                    <Item key={`winner-${index}`} sx={{ marginX: 0.5 }}>
+                      {account_id} ({utils.format.formatNearAmount(amount)}Ⓝ)
                     </Item>
-                  )
-                )}
+                  ))}
               </Box>
             </Box>
             <Box
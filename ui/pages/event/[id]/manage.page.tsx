@@ -105,11 +105,11 @@ const ManageEventPage = (): JSX.Element => {
             <Item>
               Winners ={" "}
               {event.prizes
-                .filter((prize) => Boolean(prize.winner_account_id))
+                .flatMap((prize) => prize.winners)
                 .map(
-                  (prize) =>
-                    `${prize.winner_account_id}(${utils.format.formatNearAmount(
-                      prize.prize_type.amount,
+                  (winner) =>
+                    `${winner.account_id}(${utils.format.formatNearAmount(
+                      winner.amount,
                       3
                     )}N)`
                 )
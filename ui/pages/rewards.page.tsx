@@ -70,14 +70,16 @@ const Page = (): JSX.Element => {
         `Couldn't get event from store with id '${reward.event_id}'`
       );
 
-    const prize = event.prizes[reward.prize_index];
+    const winner =
+      event.prizes[reward.prize_index]?.winners[reward.slot_index];
 
-    if (!prize)
+    if (!winner)
       throw new Error(
-        `Couldn't get prize from store with id '${reward.event_id}' & index '${reward.prize_index}'`
+        `Couldn't get prize from store with id '${reward.event_id}' & index '${reward.prize_index}' & slot '${reward.slot_index}'`
       );
 
-    return prize.prize_type.amount;
+    // every slot has its own amount, shares of split pot differ
+    return winner.amount;
   };
 
   const claimReward = async (reward: EventPrize): Promise<void> => {
@@ -122,7 +124,7 @@ const Page = (): JSX.Element => {
         <Box>
           {rewards.map((r) => (
             <Box
-              key={`reward-${r.event_id}:${r.prize_index}`}
+              key={`reward-${r.event_id}:${r.prize_index}:${r.slot_index}`}
               sx={{
                 display: "flex",
                 justifyContent: "center",
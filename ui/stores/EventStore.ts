@@ -102,7 +102,7 @@ export class EventStore {
       account_id: account.accountId,
     });
 
-    ownedEvents.forEach((e) => {
+    ownedEvents.items.forEach((e) => {
       this.addEvent(e);
     });
   };
@@ -116,7 +116,7 @@ export class EventStore {
       account_id: account.accountId,
     });
 
-    ownedEvents.forEach((e) => {
+    ownedEvents.items.forEach((e) => {
       this.addEvent(e);
       this.addParticipatedEvent(e.id);
     });
@@ -72,7 +72,7 @@ export class RewardStore {
 
     const contract = this.contractService.raffler(account);
 
-    const rewards = await contract.get_account_unclaimed_prizes({
+    const { items: rewards } = await contract.get_account_unclaimed_prizes({
       account_id: this.authStore.account.id,
     });
 
@@ -66,16 +66,61 @@ export type PublicKey = string;
 * Raw type for timestamp in nanoseconds
 */
 export type Timestamp = u64;
+export type TimestampMs = u64;
 export type EventId = u64;
+export type SeriesId = u64;
+export interface Fees {
+  prize_fee_bps: u32;
+  ticket_fee_bps: u32;
+}
+export interface Config {
+  min_near_prize_amount: U128;
+  max_prizes_per_event: u64;
+  min_title_length: u64;
+  max_title_length: u64;
+  max_pagination_limit: u64;
+  max_event_extension: u64;
+  only_verified_creators: boolean;
+  fees: Fees;
+}
+export type BlocklistEntry = BlocklistEntryAccount | BlocklistEntrySubaccounts;
+export interface BlocklistEntryAccount {
+  type: "Account";
+  account_id: AccountId;
+}
+export interface BlocklistEntrySubaccounts {
+  type: "Subaccounts";
+  account_id: AccountId;
+}
+export interface JsonCreator {
+  account_id: AccountId;
+  display_name: string;
+}
+export interface EventMetadata {
+  description?: string;
+  media?: string;
+  media_hash?: Base64VecU8;
+  link?: string;
+  tags: string[];
+  terms?: string;
+}
 export interface JsonEvent {
   id: EventId;
   title: string;
+  metadata?: EventMetadata;
   started_at: TimestampMs;
   ended_at: TimestampMs;
   status: EventStatus;
+  series_id?: SeriesId;
+  raffled_at?: TimestampMs;
   prizes: Prize[];
   participants_amount: u64;
   owner_id: AccountId;
+  verified: boolean;
+  pending_owner_id?: AccountId;
+  ticket_price: U128;
+  min_participants: u64;
+  fees: Fees;
 }
 export enum EventStatus {
   Configuration = "Configuration",
@@ -83,38 +128,280 @@ export enum EventStatus {
   Active = "Active",
   Raffling = "Raffling",
   Claiming = "Claiming",
+  Failed = "Failed",
+  Archived = "Archived",
+}
+export enum EventOrder {
+  StartedAt = "StartedAt",
+  EndedAt = "EndedAt",
+}
+export enum EventRole {
+  Admin = "Admin",
+  Moderator = "Moderator",
+}
+export interface JsonEventRole {
+  account_id: AccountId;
+  role: EventRole;
 }
 export interface EventPrize {
   event_id: EventId;
   prize_index: u64;
+  slot_index: u64;
 }
-export type PrizeType = PrizeTypeNear;
-export interface PrizeTypeNear {
-  tag: "near",
-  val: PrizeTypeNear,
+export type PrizeType = PrizeTypeNEAR | PrizeTypeSplitPot;
+export interface PrizeTypeNEAR {
+  type: "NEAR";
+  amount: U128;
 }
-export interface PrizeTypeNear {
+export interface PrizeTypeSplitPot {
+  type: "SplitPot";
   amount: U128;
+  split: PrizeSplit;
+}
+export interface PrizeDefinition {
+  prize_type: PrizeType;
+  quantity?: u64;
+}
+export type PrizeSplit = PrizeSplitEqual | PrizeSplitPercentage;
+export interface PrizeSplitEqual {
+  type: "Equal";
+  winners: u64;
+}
+export interface PrizeSplitPercentage {
+  type: "Percentage";
+  shares_bps: u32[];
 }
 export interface Prize {
   prize_type: PrizeType;
-  winner_account_id?: AccountId;
+  quantity: u64;
+  winners: PrizeWinner[];
+}
+export interface PrizeWinner {
+  account_id: AccountId;
+  amount: U128;
+  claimed: boolean;
+  claimed_at?: TimestampMs;
+}
+export interface JsonEventWinner {
+  prize_index: u64;
+  slot_index: u64;
+  prize_type: PrizeType;
+  amount: U128;
+  winner_account_id: AccountId;
+  blocked: boolean;
   claimed: boolean;
+  claimed_at?: TimestampMs;
 }
-export type TimestampMs = u64;
-export interface Pagination {
-  page: u64;
+export interface SeriesSchedule {
+  start_time: TimestampMs;
+  duration: u64;
+  interval: u64;
+  occurrences: u64;
+}
+export interface JsonSeries {
+  id: SeriesId;
+  owner_id: AccountId;
+  title: string;
+  metadata: EventMetadata;
+  ticket_price: U128;
+  prizes: U128[];
+  schedule: SeriesSchedule;
+  prize_budget: U128;
+  events: JsonEvent[];
+}
+export interface StorageBalance {
+  total: U128;
+  available: U128;
+}
+export interface StorageBalanceBounds {
+  min: U128;
+  max?: U128;
+}
+export interface CursorPagination<K> {
+  from_key?: K;
   limit: u64;
 }
+export interface Page<T, K> {
+  items: T[];
+  next_key?: K;
+  total: u64;
+}
 
 export class Contract {
   
   constructor(public account: Account, public readonly contractId: string){}
   
+  async set_admin(args: {
+    admin_id: AccountId;
+  }, options?: ChangeMethodOptions): Promise<void> {
+    return providers.getTransactionLastResult(await this.set_adminRaw(args, options));
+  }
+  set_adminRaw(args: {
+    admin_id: AccountId;
+  }, options?: ChangeMethodOptions): Promise<providers.FinalExecutionOutcome> {
+    return this.account.functionCall({contractId: this.contractId, methodName: "set_admin", args, ...options});
+  }
+  set_adminTx(args: {
+    admin_id: AccountId;
+  }, options?: ChangeMethodOptions): transactions.Action {
+    return transactions.functionCall("set_admin", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
+  }
+  async pause(args = {}, options?: ChangeMethodOptions): Promise<void> {
+    return providers.getTransactionLastResult(await this.pauseRaw(args, options));
+  }
+  pauseRaw(args = {}, options?: ChangeMethodOptions): Promise<providers.FinalExecutionOutcome> {
+    return this.account.functionCall({contractId: this.contractId, methodName: "pause", args, ...options});
+  }
+  pauseTx(args = {}, options?: ChangeMethodOptions): transactions.Action {
+    return transactions.functionCall("pause", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
+  }
+  async unpause(args = {}, options?: ChangeMethodOptions): Promise<void> {
+    return providers.getTransactionLastResult(await this.unpauseRaw(args, options));
+  }
+  unpauseRaw(args = {}, options?: ChangeMethodOptions): Promise<providers.FinalExecutionOutcome> {
+    return this.account.functionCall({contractId: this.contractId, methodName: "unpause", args, ...options});
+  }
+  unpauseTx(args = {}, options?: ChangeMethodOptions): transactions.Action {
+    return transactions.functionCall("unpause", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
+  }
+  async set_config(args: {
+    config: Config;
+  }, options?: ChangeMethodOptions): Promise<void> {
+    return providers.getTransactionLastResult(await this.set_configRaw(args, options));
+  }
+  set_configRaw(args: {
+    config: Config;
+  }, options?: ChangeMethodOptions): Promise<providers.FinalExecutionOutcome> {
+    return this.account.functionCall({contractId: this.contractId, methodName: "set_config", args, ...options});
+  }
+  set_configTx(args: {
+    config: Config;
+  }, options?: ChangeMethodOptions): transactions.Action {
+    return transactions.functionCall("set_config", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
+  }
+  async withdraw_fees(args: {
+    amount: U128;
+    receiver_id: AccountId;
+  }, options?: ChangeMethodOptions): Promise<void> {
+    return providers.getTransactionLastResult(await this.withdraw_feesRaw(args, options));
+  }
+  withdraw_feesRaw(args: {
+    amount: U128;
+    receiver_id: AccountId;
+  }, options?: ChangeMethodOptions): Promise<providers.FinalExecutionOutcome> {
+    return this.account.functionCall({contractId: this.contractId, methodName: "withdraw_fees", args, ...options});
+  }
+  withdraw_feesTx(args: {
+    amount: U128;
+    receiver_id: AccountId;
+  }, options?: ChangeMethodOptions): transactions.Action {
+    return transactions.functionCall("withdraw_fees", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
+  }
+  async register_creator(args: {
+    account_id: AccountId;
+    display_name: string;
+  }, options?: ChangeMethodOptions): Promise<void> {
+    return providers.getTransactionLastResult(await this.register_creatorRaw(args, options));
+  }
+  register_creatorRaw(args: {
+    account_id: AccountId;
+    display_name: string;
+  }, options?: ChangeMethodOptions): Promise<providers.FinalExecutionOutcome> {
+    return this.account.functionCall({contractId: this.contractId, methodName: "register_creator", args, ...options});
+  }
+  register_creatorTx(args: {
+    account_id: AccountId;
+    display_name: string;
+  }, options?: ChangeMethodOptions): transactions.Action {
+    return transactions.functionCall("register_creator", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
+  }
+  async unregister_creator(args: {
+    account_id: AccountId;
+  }, options?: ChangeMethodOptions): Promise<void> {
+    return providers.getTransactionLastResult(await this.unregister_creatorRaw(args, options));
+  }
+  unregister_creatorRaw(args: {
+    account_id: AccountId;
+  }, options?: ChangeMethodOptions): Promise<providers.FinalExecutionOutcome> {
+    return this.account.functionCall({contractId: this.contractId, methodName: "unregister_creator", args, ...options});
+  }
+  unregister_creatorTx(args: {
+    account_id: AccountId;
+  }, options?: ChangeMethodOptions): transactions.Action {
+    return transactions.functionCall("unregister_creator", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
+  }
+  async add_to_blocklist(args: {
+    entries: BlocklistEntry[];
+  }, options?: ChangeMethodOptions): Promise<void> {
+    return providers.getTransactionLastResult(await this.add_to_blocklistRaw(args, options));
+  }
+  add_to_blocklistRaw(args: {
+    entries: BlocklistEntry[];
+  }, options?: ChangeMethodOptions): Promise<providers.FinalExecutionOutcome> {
+    return this.account.functionCall({contractId: this.contractId, methodName: "add_to_blocklist", args, ...options});
+  }
+  add_to_blocklistTx(args: {
+    entries: BlocklistEntry[];
+  }, options?: ChangeMethodOptions): transactions.Action {
+    return transactions.functionCall("add_to_blocklist", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
+  }
+  async remove_from_blocklist(args: {
+    entries: BlocklistEntry[];
+  }, options?: ChangeMethodOptions): Promise<void> {
+    return providers.getTransactionLastResult(await this.remove_from_blocklistRaw(args, options));
+  }
+  remove_from_blocklistRaw(args: {
+    entries: BlocklistEntry[];
+  }, options?: ChangeMethodOptions): Promise<providers.FinalExecutionOutcome> {
+    return this.account.functionCall({contractId: this.contractId, methodName: "remove_from_blocklist", args, ...options});
+  }
+  remove_from_blocklistTx(args: {
+    entries: BlocklistEntry[];
+  }, options?: ChangeMethodOptions): transactions.Action {
+    return transactions.functionCall("remove_from_blocklist", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
+  }
+  get_admin(args = {}, options?: ViewFunctionOptions): Promise<AccountId> {
+    return this.account.viewFunction(this.contractId, "get_admin", args, options);
+  }
+  is_paused(args = {}, options?: ViewFunctionOptions): Promise<boolean> {
+    return this.account.viewFunction(this.contractId, "is_paused", args, options);
+  }
+  get_config(args = {}, options?: ViewFunctionOptions): Promise<Config> {
+    return this.account.viewFunction(this.contractId, "get_config", args, options);
+  }
+  get_accrued_fees(args = {}, options?: ViewFunctionOptions): Promise<U128> {
+    return this.account.viewFunction(this.contractId, "get_accrued_fees", args, options);
+  }
+  get_creator(args: {
+    account_id: AccountId;
+  }, options?: ViewFunctionOptions): Promise<JsonCreator | null> {
+    return this.account.viewFunction(this.contractId, "get_creator", args, options);
+  }
+  get_creators(args: {
+    pagination?: CursorPagination<AccountId>;
+  }, options?: ViewFunctionOptions): Promise<Page<JsonCreator, AccountId>> {
+    return this.account.viewFunction(this.contractId, "get_creators", args, options);
+  }
+  is_blocked(args: {
+    account_id: AccountId;
+  }, options?: ViewFunctionOptions): Promise<boolean> {
+    return this.account.viewFunction(this.contractId, "is_blocked", args, options);
+  }
+  get_blocked_accounts(args: {
+    pagination?: CursorPagination<AccountId>;
+  }, options?: ViewFunctionOptions): Promise<Page<AccountId, AccountId>> {
+    return this.account.viewFunction(this.contractId, "get_blocked_accounts", args, options);
+  }
+  get_blocked_subaccounts(args: {
+    pagination?: CursorPagination<AccountId>;
+  }, options?: ViewFunctionOptions): Promise<Page<AccountId, AccountId>> {
+    return this.account.viewFunction(this.contractId, "get_blocked_subaccounts", args, options);
+  }
   async add_event(args: {
     title: string;
     start_time?: TimestampMs;
     end_time?: TimestampMs;
+    metadata?: EventMetadata;
   }, options?: ChangeMethodOptions): Promise<EventId> {
     return providers.getTransactionLastResult(await this.add_eventRaw(args, options));
   }
@@ -122,6 +409,7 @@ export class Contract {
     title: string;
     start_time?: TimestampMs;
     end_time?: TimestampMs;
+    metadata?: EventMetadata;
   }, options?: ChangeMethodOptions): Promise<providers.FinalExecutionOutcome> {
     return this.account.functionCall({contractId: this.contractId, methodName: "add_event", args, ...options});
   }
@@ -129,6 +417,7 @@ export class Contract {
     title: string;
     start_time?: TimestampMs;
     end_time?: TimestampMs;
+    metadata?: EventMetadata;
   }, options?: ChangeMethodOptions): transactions.Action {
     return transactions.functionCall("add_event", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
   }
@@ -153,6 +442,39 @@ export class Contract {
   }, options?: ChangeMethodOptions): transactions.Action {
     return transactions.functionCall("set_event_time", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
   }
+  async extend_event(args: {
+    event_id: EventId;
+    end_time: TimestampMs;
+  }, options?: ChangeMethodOptions): Promise<void> {
+    return providers.getTransactionLastResult(await this.extend_eventRaw(args, options));
+  }
+  extend_eventRaw(args: {
+    event_id: EventId;
+    end_time: TimestampMs;
+  }, options?: ChangeMethodOptions): Promise<providers.FinalExecutionOutcome> {
+    return this.account.functionCall({contractId: this.contractId, methodName: "extend_event", args, ...options});
+  }
+  extend_eventTx(args: {
+    event_id: EventId;
+    end_time: TimestampMs;
+  }, options?: ChangeMethodOptions): transactions.Action {
+    return transactions.functionCall("extend_event", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
+  }
+  async end_event(args: {
+    event_id: EventId;
+  }, options?: ChangeMethodOptions): Promise<void> {
+    return providers.getTransactionLastResult(await this.end_eventRaw(args, options));
+  }
+  end_eventRaw(args: {
+    event_id: EventId;
+  }, options?: ChangeMethodOptions): Promise<providers.FinalExecutionOutcome> {
+    return this.account.functionCall({contractId: this.contractId, methodName: "end_event", args, ...options});
+  }
+  end_eventTx(args: {
+    event_id: EventId;
+  }, options?: ChangeMethodOptions): transactions.Action {
+    return transactions.functionCall("end_event", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
+  }
   async set_event_visible(args: {
     event_id: EventId;
   }, options?: ChangeMethodOptions): Promise<void> {
@@ -168,24 +490,168 @@ export class Contract {
   }, options?: ChangeMethodOptions): transactions.Action {
     return transactions.functionCall("set_event_visible", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
   }
+  async set_event_ticket_price(args: {
+    event_id: EventId;
+    ticket_price: U128;
+  }, options?: ChangeMethodOptions): Promise<void> {
+    return providers.getTransactionLastResult(await this.set_event_ticket_priceRaw(args, options));
+  }
+  set_event_ticket_priceRaw(args: {
+    event_id: EventId;
+    ticket_price: U128;
+  }, options?: ChangeMethodOptions): Promise<providers.FinalExecutionOutcome> {
+    return this.account.functionCall({contractId: this.contractId, methodName: "set_event_ticket_price", args, ...options});
+  }
+  set_event_ticket_priceTx(args: {
+    event_id: EventId;
+    ticket_price: U128;
+  }, options?: ChangeMethodOptions): transactions.Action {
+    return transactions.functionCall("set_event_ticket_price", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
+  }
+  async set_event_min_participants(args: {
+    event_id: EventId;
+    min_participants: u64;
+  }, options?: ChangeMethodOptions): Promise<void> {
+    return providers.getTransactionLastResult(await this.set_event_min_participantsRaw(args, options));
+  }
+  set_event_min_participantsRaw(args: {
+    event_id: EventId;
+    min_participants: u64;
+  }, options?: ChangeMethodOptions): Promise<providers.FinalExecutionOutcome> {
+    return this.account.functionCall({contractId: this.contractId, methodName: "set_event_min_participants", args, ...options});
+  }
+  set_event_min_participantsTx(args: {
+    event_id: EventId;
+    min_participants: u64;
+  }, options?: ChangeMethodOptions): transactions.Action {
+    return transactions.functionCall("set_event_min_participants", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
+  }
+  async set_event_metadata(args: {
+    event_id: EventId;
+    metadata: EventMetadata;
+  }, options?: ChangeMethodOptions): Promise<void> {
+    return providers.getTransactionLastResult(await this.set_event_metadataRaw(args, options));
+  }
+  set_event_metadataRaw(args: {
+    event_id: EventId;
+    metadata: EventMetadata;
+  }, options?: ChangeMethodOptions): Promise<providers.FinalExecutionOutcome> {
+    return this.account.functionCall({contractId: this.contractId, methodName: "set_event_metadata", args, ...options});
+  }
+  set_event_metadataTx(args: {
+    event_id: EventId;
+    metadata: EventMetadata;
+  }, options?: ChangeMethodOptions): transactions.Action {
+    return transactions.functionCall("set_event_metadata", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
+  }
+  async fund_event_storage(args: {
+    event_id: EventId;
+  }, options?: ChangeMethodOptions): Promise<void> {
+    return providers.getTransactionLastResult(await this.fund_event_storageRaw(args, options));
+  }
+  fund_event_storageRaw(args: {
+    event_id: EventId;
+  }, options?: ChangeMethodOptions): Promise<providers.FinalExecutionOutcome> {
+    return this.account.functionCall({contractId: this.contractId, methodName: "fund_event_storage", args, ...options});
+  }
+  fund_event_storageTx(args: {
+    event_id: EventId;
+  }, options?: ChangeMethodOptions): transactions.Action {
+    return transactions.functionCall("fund_event_storage", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
+  }
   async add_near_prize(args: {
     event_id: EventId;
     amount: U128;
+    quantity?: u64;
   }, options?: ChangeMethodOptions): Promise<void> {
     return providers.getTransactionLastResult(await this.add_near_prizeRaw(args, options));
   }
   add_near_prizeRaw(args: {
     event_id: EventId;
     amount: U128;
+    quantity?: u64;
   }, options?: ChangeMethodOptions): Promise<providers.FinalExecutionOutcome> {
     return this.account.functionCall({contractId: this.contractId, methodName: "add_near_prize", args, ...options});
   }
   add_near_prizeTx(args: {
     event_id: EventId;
     amount: U128;
+    quantity?: u64;
   }, options?: ChangeMethodOptions): transactions.Action {
     return transactions.functionCall("add_near_prize", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
   }
+  async add_split_pot_prize(args: {
+    event_id: EventId;
+    amount: U128;
+    split: PrizeSplit;
+  }, options?: ChangeMethodOptions): Promise<void> {
+    return providers.getTransactionLastResult(await this.add_split_pot_prizeRaw(args, options));
+  }
+  add_split_pot_prizeRaw(args: {
+    event_id: EventId;
+    amount: U128;
+    split: PrizeSplit;
+  }, options?: ChangeMethodOptions): Promise<providers.FinalExecutionOutcome> {
+    return this.account.functionCall({contractId: this.contractId, methodName: "add_split_pot_prize", args, ...options});
+  }
+  add_split_pot_prizeTx(args: {
+    event_id: EventId;
+    amount: U128;
+    split: PrizeSplit;
+  }, options?: ChangeMethodOptions): transactions.Action {
+    return transactions.functionCall("add_split_pot_prize", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
+  }
+  async create_event_full(args: {
+    title: string;
+    start_time?: TimestampMs;
+    end_time?: TimestampMs;
+    metadata?: EventMetadata;
+    prizes: PrizeDefinition[];
+    publish: boolean;
+  }, options?: ChangeMethodOptions): Promise<EventId> {
+    return providers.getTransactionLastResult(await this.create_event_fullRaw(args, options));
+  }
+  create_event_fullRaw(args: {
+    title: string;
+    start_time?: TimestampMs;
+    end_time?: TimestampMs;
+    metadata?: EventMetadata;
+    prizes: PrizeDefinition[];
+    publish: boolean;
+  }, options?: ChangeMethodOptions): Promise<providers.FinalExecutionOutcome> {
+    return this.account.functionCall({contractId: this.contractId, methodName: "create_event_full", args, ...options});
+  }
+  create_event_fullTx(args: {
+    title: string;
+    start_time?: TimestampMs;
+    end_time?: TimestampMs;
+    metadata?: EventMetadata;
+    prizes: PrizeDefinition[];
+    publish: boolean;
+  }, options?: ChangeMethodOptions): transactions.Action {
+    return transactions.functionCall("create_event_full", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
+  }
+  async clone_event(args: {
+    source_event_id: EventId;
+    start_time: TimestampMs;
+    end_time: TimestampMs;
+  }, options?: ChangeMethodOptions): Promise<EventId> {
+    return providers.getTransactionLastResult(await this.clone_eventRaw(args, options));
+  }
+  clone_eventRaw(args: {
+    source_event_id: EventId;
+    start_time: TimestampMs;
+    end_time: TimestampMs;
+  }, options?: ChangeMethodOptions): Promise<providers.FinalExecutionOutcome> {
+    return this.account.functionCall({contractId: this.contractId, methodName: "clone_event", args, ...options});
+  }
+  clone_eventTx(args: {
+    source_event_id: EventId;
+    start_time: TimestampMs;
+    end_time: TimestampMs;
+  }, options?: ChangeMethodOptions): transactions.Action {
+    return transactions.functionCall("clone_event", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
+  }
   async join_event(args: {
     event_id: EventId;
   }, options?: ChangeMethodOptions): Promise<void> {
@@ -201,6 +667,24 @@ export class Contract {
   }, options?: ChangeMethodOptions): transactions.Action {
     return transactions.functionCall("join_event", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
   }
+  async ban_event_participant(args: {
+    event_id: EventId;
+    account_id: AccountId;
+  }, options?: ChangeMethodOptions): Promise<void> {
+    return providers.getTransactionLastResult(await this.ban_event_participantRaw(args, options));
+  }
+  ban_event_participantRaw(args: {
+    event_id: EventId;
+    account_id: AccountId;
+  }, options?: ChangeMethodOptions): Promise<providers.FinalExecutionOutcome> {
+    return this.account.functionCall({contractId: this.contractId, methodName: "ban_event_participant", args, ...options});
+  }
+  ban_event_participantTx(args: {
+    event_id: EventId;
+    account_id: AccountId;
+  }, options?: ChangeMethodOptions): transactions.Action {
+    return transactions.functionCall("ban_event_participant", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
+  }
   async raffle_event_prizes(args: {
     event_id: EventId;
   }, options?: ChangeMethodOptions): Promise<EventPrize[]> {
@@ -231,54 +715,935 @@ export class Contract {
   }, options?: ChangeMethodOptions): transactions.Action {
     return transactions.functionCall("claim_prize", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
   }
-  get_owner_events(args: {
+  async archive_event(args: {
+    event_id: EventId;
+    limit?: u64;
+  }, options?: ChangeMethodOptions): Promise<boolean> {
+    return providers.getTransactionLastResult(await this.archive_eventRaw(args, options));
+  }
+  archive_eventRaw(args: {
+    event_id: EventId;
+    limit?: u64;
+  }, options?: ChangeMethodOptions): Promise<providers.FinalExecutionOutcome> {
+    return this.account.functionCall({contractId: this.contractId, methodName: "archive_event", args, ...options});
+  }
+  archive_eventTx(args: {
+    event_id: EventId;
+    limit?: u64;
+  }, options?: ChangeMethodOptions): transactions.Action {
+    return transactions.functionCall("archive_event", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
+  }
+  async propose_event_owner(args: {
+    event_id: EventId;
+    new_owner_id?: AccountId;
+  }, options?: ChangeMethodOptions): Promise<void> {
+    return providers.getTransactionLastResult(await this.propose_event_ownerRaw(args, options));
+  }
+  propose_event_ownerRaw(args: {
+    event_id: EventId;
+    new_owner_id?: AccountId;
+  }, options?: ChangeMethodOptions): Promise<providers.FinalExecutionOutcome> {
+    return this.account.functionCall({contractId: this.contractId, methodName: "propose_event_owner", args, ...options});
+  }
+  propose_event_ownerTx(args: {
+    event_id: EventId;
+    new_owner_id?: AccountId;
+  }, options?: ChangeMethodOptions): transactions.Action {
+    return transactions.functionCall("propose_event_owner", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
+  }
+  async accept_event_owner(args: {
+    event_id: EventId;
+  }, options?: ChangeMethodOptions): Promise<void> {
+    return providers.getTransactionLastResult(await this.accept_event_ownerRaw(args, options));
+  }
+  accept_event_ownerRaw(args: {
+    event_id: EventId;
+  }, options?: ChangeMethodOptions): Promise<providers.FinalExecutionOutcome> {
+    return this.account.functionCall({contractId: this.contractId, methodName: "accept_event_owner", args, ...options});
+  }
+  accept_event_ownerTx(args: {
+    event_id: EventId;
+  }, options?: ChangeMethodOptions): transactions.Action {
+    return transactions.functionCall("accept_event_owner", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
+  }
+  async grant_event_role(args: {
+    event_id: EventId;
     account_id: AccountId;
-    pagination?: Pagination;
-  }, options?: ViewFunctionOptions): Promise<JsonEvent[]> {
-    return this.account.viewFunction(this.contractId, "get_owner_events", args, options);
+    role: EventRole;
+  }, options?: ChangeMethodOptions): Promise<void> {
+    return providers.getTransactionLastResult(await this.grant_event_roleRaw(args, options));
   }
-  get_participant_events(args: {
+  grant_event_roleRaw(args: {
+    event_id: EventId;
     account_id: AccountId;
-    pagination?: Pagination;
-  }, options?: ViewFunctionOptions): Promise<JsonEvent[]> {
-    return this.account.viewFunction(this.contractId, "get_participant_events", args, options);
+    role: EventRole;
+  }, options?: ChangeMethodOptions): Promise<providers.FinalExecutionOutcome> {
+    return this.account.functionCall({contractId: this.contractId, methodName: "grant_event_role", args, ...options});
   }
-  get_event(args: {
+  grant_event_roleTx(args: {
     event_id: EventId;
-  }, options?: ViewFunctionOptions): Promise<JsonEvent | null> {
-    return this.account.viewFunction(this.contractId, "get_event", args, options);
+    account_id: AccountId;
+    role: EventRole;
+  }, options?: ChangeMethodOptions): transactions.Action {
+    return transactions.functionCall("grant_event_role", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
   }
-  get_account_unclaimed_prizes(args: {
+  async revoke_event_role(args: {
+    event_id: EventId;
     account_id: AccountId;
-    pagination?: Pagination;
-  }, options?: ViewFunctionOptions): Promise<EventPrize[]> {
-    return this.account.viewFunction(this.contractId, "get_account_unclaimed_prizes", args, options);
+  }, options?: ChangeMethodOptions): Promise<void> {
+    return providers.getTransactionLastResult(await this.revoke_event_roleRaw(args, options));
+  }
+  revoke_event_roleRaw(args: {
+    event_id: EventId;
+    account_id: AccountId;
+  }, options?: ChangeMethodOptions): Promise<providers.FinalExecutionOutcome> {
+    return this.account.functionCall({contractId: this.contractId, methodName: "revoke_event_role", args, ...options});
+  }
+  revoke_event_roleTx(args: {
+    event_id: EventId;
+    account_id: AccountId;
+  }, options?: ChangeMethodOptions): transactions.Action {
+    return transactions.functionCall("revoke_event_role", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
+  }
+  get_owner_events(args: {
+    account_id: AccountId;
+    pagination?: CursorPagination<EventId>;
+  }, options?: ViewFunctionOptions): Promise<Page<JsonEvent, EventId>> {
+    return this.account.viewFunction(this.contractId, "get_owner_events", args, options);
+  }
+  get_participant_events(args: {
+    account_id: AccountId;
+    pagination?: CursorPagination<EventId>;
+  }, options?: ViewFunctionOptions): Promise<Page<JsonEvent, EventId>> {
+    return this.account.viewFunction(this.contractId, "get_participant_events", args, options);
+  }
+  get_events(args: {
+    status?: EventStatus;
+    order_by?: EventOrder;
+    pagination?: CursorPagination<EventId>;
+  }, options?: ViewFunctionOptions): Promise<Page<JsonEvent, EventId>> {
+    return this.account.viewFunction(this.contractId, "get_events", args, options);
+  }
+  get_event(args: {
+    event_id: EventId;
+  }, options?: ViewFunctionOptions): Promise<JsonEvent | null> {
+    return this.account.viewFunction(this.contractId, "get_event", args, options);
+  }
+  get_account_unclaimed_prizes(args: {
+    account_id: AccountId;
+    pagination?: CursorPagination<EventPrize>;
+  }, options?: ViewFunctionOptions): Promise<Page<EventPrize, EventPrize>> {
+    return this.account.viewFunction(this.contractId, "get_account_unclaimed_prizes", args, options);
+  }
+  get_event_roles(args: {
+    event_id: EventId;
+    pagination?: CursorPagination<AccountId>;
+  }, options?: ViewFunctionOptions): Promise<Page<JsonEventRole, AccountId>> {
+    return this.account.viewFunction(this.contractId, "get_event_roles", args, options);
+  }
+  get_event_sponsored_joins(args: {
+    event_id: EventId;
+  }, options?: ViewFunctionOptions): Promise<u64> {
+    return this.account.viewFunction(this.contractId, "get_event_sponsored_joins", args, options);
+  }
+  get_event_winners(args: {
+    event_id: EventId;
+  }, options?: ViewFunctionOptions): Promise<JsonEventWinner[]> {
+    return this.account.viewFunction(this.contractId, "get_event_winners", args, options);
+  }
+  get_event_participants(args: {
+    event_id: EventId;
+    pagination?: CursorPagination<u64>;
+  }, options?: ViewFunctionOptions): Promise<Page<AccountId, u64>> {
+    return this.account.viewFunction(this.contractId, "get_event_participants", args, options);
+  }
+  get_event_banned_accounts(args: {
+    event_id: EventId;
+    pagination?: CursorPagination<u64>;
+  }, options?: ViewFunctionOptions): Promise<Page<AccountId, u64>> {
+    return this.account.viewFunction(this.contractId, "get_event_banned_accounts", args, options);
+  }
+  get_event_participant_count(args: {
+    event_id: EventId;
+  }, options?: ViewFunctionOptions): Promise<u64> {
+    return this.account.viewFunction(this.contractId, "get_event_participant_count", args, options);
+  }
+  are_participants(args: {
+    event_id: EventId;
+    account_ids: AccountId[];
+  }, options?: ViewFunctionOptions): Promise<boolean[]> {
+    return this.account.viewFunction(this.contractId, "are_participants", args, options);
+  }
+  is_user_joined_event(args: {
+    account_id: AccountId;
+    event_id: EventId;
+  }, options?: ViewFunctionOptions): Promise<boolean> {
+    return this.account.viewFunction(this.contractId, "is_user_joined_event", args, options);
+  }
+  async create_series(args: {
+    title: string;
+    metadata?: EventMetadata;
+    ticket_price?: U128;
+    prizes: U128[];
+    schedule: SeriesSchedule;
+  }, options?: ChangeMethodOptions): Promise<SeriesId> {
+    return providers.getTransactionLastResult(await this.create_seriesRaw(args, options));
+  }
+  create_seriesRaw(args: {
+    title: string;
+    metadata?: EventMetadata;
+    ticket_price?: U128;
+    prizes: U128[];
+    schedule: SeriesSchedule;
+  }, options?: ChangeMethodOptions): Promise<providers.FinalExecutionOutcome> {
+    return this.account.functionCall({contractId: this.contractId, methodName: "create_series", args, ...options});
+  }
+  create_seriesTx(args: {
+    title: string;
+    metadata?: EventMetadata;
+    ticket_price?: U128;
+    prizes: U128[];
+    schedule: SeriesSchedule;
+  }, options?: ChangeMethodOptions): transactions.Action {
+    return transactions.functionCall("create_series", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
+  }
+  async advance_series(args: {
+    series_id: SeriesId;
+  }, options?: ChangeMethodOptions): Promise<EventId> {
+    return providers.getTransactionLastResult(await this.advance_seriesRaw(args, options));
+  }
+  advance_seriesRaw(args: {
+    series_id: SeriesId;
+  }, options?: ChangeMethodOptions): Promise<providers.FinalExecutionOutcome> {
+    return this.account.functionCall({contractId: this.contractId, methodName: "advance_series", args, ...options});
+  }
+  advance_seriesTx(args: {
+    series_id: SeriesId;
+  }, options?: ChangeMethodOptions): transactions.Action {
+    return transactions.functionCall("advance_series", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
+  }
+  get_series(args: {
+    series_id: SeriesId;
+  }, options?: ViewFunctionOptions): Promise<JsonSeries | null> {
+    return this.account.viewFunction(this.contractId, "get_series", args, options);
+  }
+  async storage_deposit(args: {
+    account_id?: AccountId;
+    registration_only?: boolean;
+  }, options?: ChangeMethodOptions): Promise<StorageBalance> {
+    return providers.getTransactionLastResult(await this.storage_depositRaw(args, options));
+  }
+  storage_depositRaw(args: {
+    account_id?: AccountId;
+    registration_only?: boolean;
+  }, options?: ChangeMethodOptions): Promise<providers.FinalExecutionOutcome> {
+    return this.account.functionCall({contractId: this.contractId, methodName: "storage_deposit", args, ...options});
+  }
+  storage_depositTx(args: {
+    account_id?: AccountId;
+    registration_only?: boolean;
+  }, options?: ChangeMethodOptions): transactions.Action {
+    return transactions.functionCall("storage_deposit", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
+  }
+  async storage_withdraw(args: {
+    amount?: U128;
+  }, options?: ChangeMethodOptions): Promise<StorageBalance> {
+    return providers.getTransactionLastResult(await this.storage_withdrawRaw(args, options));
+  }
+  storage_withdrawRaw(args: {
+    amount?: U128;
+  }, options?: ChangeMethodOptions): Promise<providers.FinalExecutionOutcome> {
+    return this.account.functionCall({contractId: this.contractId, methodName: "storage_withdraw", args, ...options});
+  }
+  storage_withdrawTx(args: {
+    amount?: U128;
+  }, options?: ChangeMethodOptions): transactions.Action {
+    return transactions.functionCall("storage_withdraw", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
+  }
+  async storage_unregister(args: {
+    force?: boolean;
+  }, options?: ChangeMethodOptions): Promise<boolean> {
+    return providers.getTransactionLastResult(await this.storage_unregisterRaw(args, options));
+  }
+  storage_unregisterRaw(args: {
+    force?: boolean;
+  }, options?: ChangeMethodOptions): Promise<providers.FinalExecutionOutcome> {
+    return this.account.functionCall({contractId: this.contractId, methodName: "storage_unregister", args, ...options});
+  }
+  storage_unregisterTx(args: {
+    force?: boolean;
+  }, options?: ChangeMethodOptions): transactions.Action {
+    return transactions.functionCall("storage_unregister", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
+  }
+  storage_balance_of(args: {
+    account_id: AccountId;
+  }, options?: ViewFunctionOptions): Promise<StorageBalance | null> {
+    return this.account.viewFunction(this.contractId, "storage_balance_of", args, options);
+  }
+  storage_balance_bounds(args = {}, options?: ViewFunctionOptions): Promise<StorageBalanceBounds> {
+    return this.account.viewFunction(this.contractId, "storage_balance_bounds", args, options);
+  }
+  async new(args = {}, options?: ChangeMethodOptions): Promise<void> {
+    return providers.getTransactionLastResult(await this.newRaw(args, options));
+  }
+  newRaw(args = {}, options?: ChangeMethodOptions): Promise<providers.FinalExecutionOutcome> {
+    return this.account.functionCall({contractId: this.contractId, methodName: "new", args, ...options});
+  }
+  newTx(args = {}, options?: ChangeMethodOptions): transactions.Action {
+    return transactions.functionCall("new", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
+  }
+}
+/**
+* 
+* @contractMethod change
+*/
+export interface SetAdmin {
+  args: {
+    admin_id: AccountId;
+  };
+  options: {
+    /** Units in gas
+    * @pattern [0-9]+
+    * @default "30000000000000"
+    */
+    gas?: string;
+    /** Units in yoctoNear
+    * @default "0"
+    */
+    attachedDeposit?: Balance;
+  }
+  
+}
+export type SetAdmin__Result = void;
+/**
+* 
+* @contractMethod change
+*/
+export interface Pause {
+  args: {};
+  options: {
+    /** Units in gas
+    * @pattern [0-9]+
+    * @default "30000000000000"
+    */
+    gas?: string;
+    /** Units in yoctoNear
+    * @default "0"
+    */
+    attachedDeposit?: Balance;
+  }
+  
+}
+export type Pause__Result = void;
+/**
+* 
+* @contractMethod change
+*/
+export interface Unpause {
+  args: {};
+  options: {
+    /** Units in gas
+    * @pattern [0-9]+
+    * @default "30000000000000"
+    */
+    gas?: string;
+    /** Units in yoctoNear
+    * @default "0"
+    */
+    attachedDeposit?: Balance;
+  }
+  
+}
+export type Unpause__Result = void;
+/**
+* 
+* @contractMethod change
+*/
+export interface SetConfig {
+  args: {
+    config: Config;
+  };
+  options: {
+    /** Units in gas
+    * @pattern [0-9]+
+    * @default "30000000000000"
+    */
+    gas?: string;
+    /** Units in yoctoNear
+    * @default "0"
+    */
+    attachedDeposit?: Balance;
+  }
+  
+}
+export type SetConfig__Result = void;
+/**
+* 
+* @contractMethod change
+*/
+export interface WithdrawFees {
+  args: {
+    amount: U128;
+    receiver_id: AccountId;
+  };
+  options: {
+    /** Units in gas
+    * @pattern [0-9]+
+    * @default "30000000000000"
+    */
+    gas?: string;
+    /** Units in yoctoNear
+    * @default "0"
+    */
+    attachedDeposit?: Balance;
+  }
+  
+}
+export type WithdrawFees__Result = void;
+/**
+* 
+* @contractMethod change
+*/
+export interface RegisterCreator {
+  args: {
+    account_id: AccountId;
+    display_name: string;
+  };
+  options: {
+    /** Units in gas
+    * @pattern [0-9]+
+    * @default "30000000000000"
+    */
+    gas?: string;
+    /** Units in yoctoNear
+    * @default "0"
+    */
+    attachedDeposit?: Balance;
+  }
+  
+}
+export type RegisterCreator__Result = void;
+/**
+* 
+* @contractMethod change
+*/
+export interface UnregisterCreator {
+  args: {
+    account_id: AccountId;
+  };
+  options: {
+    /** Units in gas
+    * @pattern [0-9]+
+    * @default "30000000000000"
+    */
+    gas?: string;
+    /** Units in yoctoNear
+    * @default "0"
+    */
+    attachedDeposit?: Balance;
+  }
+  
+}
+export type UnregisterCreator__Result = void;
+/**
+* 
+* @contractMethod change
+*/
+export interface AddToBlocklist {
+  args: {
+    entries: BlocklistEntry[];
+  };
+  options: {
+    /** Units in gas
+    * @pattern [0-9]+
+    * @default "30000000000000"
+    */
+    gas?: string;
+    /** Units in yoctoNear
+    * @default "0"
+    */
+    attachedDeposit?: Balance;
+  }
+  
+}
+export type AddToBlocklist__Result = void;
+/**
+* 
+* @contractMethod change
+*/
+export interface RemoveFromBlocklist {
+  args: {
+    entries: BlocklistEntry[];
+  };
+  options: {
+    /** Units in gas
+    * @pattern [0-9]+
+    * @default "30000000000000"
+    */
+    gas?: string;
+    /** Units in yoctoNear
+    * @default "0"
+    */
+    attachedDeposit?: Balance;
+  }
+  
+}
+export type RemoveFromBlocklist__Result = void;
+/**
+* 
+* @contractMethod view
+*/
+export interface GetAdmin {
+  args: {};
+  
+}
+export type GetAdmin__Result = AccountId;
+/**
+* 
+* @contractMethod view
+*/
+export interface IsPaused {
+  args: {};
+  
+}
+export type IsPaused__Result = boolean;
+/**
+* 
+* @contractMethod view
+*/
+export interface GetConfig {
+  args: {};
+  
+}
+export type GetConfig__Result = Config;
+/**
+* 
+* @contractMethod view
+*/
+export interface GetAccruedFees {
+  args: {};
+  
+}
+export type GetAccruedFees__Result = U128;
+/**
+* 
+* @contractMethod view
+*/
+export interface GetCreator {
+  args: {
+    account_id: AccountId;
+  };
+  
+}
+export type GetCreator__Result = JsonCreator | null;
+/**
+* 
+* @contractMethod view
+*/
+export interface GetCreators {
+  args: {
+    pagination?: CursorPagination<AccountId>;
+  };
+  
+}
+export type GetCreators__Result = Page<JsonCreator, AccountId>;
+/**
+* 
+* @contractMethod view
+*/
+export interface IsBlocked {
+  args: {
+    account_id: AccountId;
+  };
+  
+}
+export type IsBlocked__Result = boolean;
+/**
+* 
+* @contractMethod view
+*/
+export interface GetBlockedAccounts {
+  args: {
+    pagination?: CursorPagination<AccountId>;
+  };
+  
+}
+export type GetBlockedAccounts__Result = Page<AccountId, AccountId>;
+/**
+* 
+* @contractMethod view
+*/
+export interface GetBlockedSubaccounts {
+  args: {
+    pagination?: CursorPagination<AccountId>;
+  };
+  
+}
+export type GetBlockedSubaccounts__Result = Page<AccountId, AccountId>;
+/**
+* 
+* @contractMethod change
+*/
+export interface AddEvent {
+  args: {
+    title: string;
+    start_time?: TimestampMs;
+    end_time?: TimestampMs;
+    metadata?: EventMetadata;
+  };
+  options: {
+    /** Units in gas
+    * @pattern [0-9]+
+    * @default "30000000000000"
+    */
+    gas?: string;
+    /** Units in yoctoNear
+    * @default "0"
+    */
+    attachedDeposit?: Balance;
+  }
+  
+}
+export type AddEvent__Result = EventId;
+/**
+* 
+* @contractMethod change
+*/
+export interface SetEventTime {
+  args: {
+    event_id: EventId;
+    start_time: TimestampMs;
+    end_time: TimestampMs;
+  };
+  options: {
+    /** Units in gas
+    * @pattern [0-9]+
+    * @default "30000000000000"
+    */
+    gas?: string;
+    /** Units in yoctoNear
+    * @default "0"
+    */
+    attachedDeposit?: Balance;
+  }
+  
+}
+export type SetEventTime__Result = void;
+/**
+* 
+* @contractMethod change
+*/
+export interface ExtendEvent {
+  args: {
+    event_id: EventId;
+    end_time: TimestampMs;
+  };
+  options: {
+    /** Units in gas
+    * @pattern [0-9]+
+    * @default "30000000000000"
+    */
+    gas?: string;
+    /** Units in yoctoNear
+    * @default "0"
+    */
+    attachedDeposit?: Balance;
+  }
+  
+}
+export type ExtendEvent__Result = void;
+/**
+* Ends the active event right away, so prizes could be raffled
+* 
+* @contractMethod change
+*/
+export interface EndEvent {
+  args: {
+    event_id: EventId;
+  };
+  options: {
+    /** Units in gas
+    * @pattern [0-9]+
+    * @default "30000000000000"
+    */
+    gas?: string;
+    /** Units in yoctoNear
+    * @default "0"
+    */
+    attachedDeposit?: Balance;
+  }
+  
+}
+export type EndEvent__Result = void;
+/**
+* 
+* @contractMethod change
+*/
+export interface SetEventVisible {
+  args: {
+    event_id: EventId;
+  };
+  options: {
+    /** Units in gas
+    * @pattern [0-9]+
+    * @default "30000000000000"
+    */
+    gas?: string;
+    /** Units in yoctoNear
+    * @default "0"
+    */
+    attachedDeposit?: Balance;
+  }
+  
+}
+export type SetEventVisible__Result = void;
+/**
+* 
+* @contractMethod change
+*/
+export interface SetEventTicketPrice {
+  args: {
+    event_id: EventId;
+    ticket_price: U128;
+  };
+  options: {
+    /** Units in gas
+    * @pattern [0-9]+
+    * @default "30000000000000"
+    */
+    gas?: string;
+    /** Units in yoctoNear
+    * @default "0"
+    */
+    attachedDeposit?: Balance;
+  }
+  
+}
+export type SetEventTicketPrice__Result = void;
+/**
+* 
+* @contractMethod change
+*/
+export interface SetEventMinParticipants {
+  args: {
+    event_id: EventId;
+    min_participants: u64;
+  };
+  options: {
+    /** Units in gas
+    * @pattern [0-9]+
+    * @default "30000000000000"
+    */
+    gas?: string;
+    /** Units in yoctoNear
+    * @default "0"
+    */
+    attachedDeposit?: Balance;
+  }
+  
+}
+export type SetEventMinParticipants__Result = void;
+/**
+* 
+* @contractMethod change
+*/
+export interface SetEventMetadata {
+  args: {
+    event_id: EventId;
+    metadata: EventMetadata;
+  };
+  options: {
+    /** Units in gas
+    * @pattern [0-9]+
+    * @default "30000000000000"
+    */
+    gas?: string;
+    /** Units in yoctoNear
+    * @default "0"
+    */
+    attachedDeposit?: Balance;
+  }
+  
+}
+export type SetEventMetadata__Result = void;
+/**
+* 
+* @contractMethod change
+*/
+export interface FundEventStorage {
+  args: {
+    event_id: EventId;
+  };
+  options: {
+    /** Units in gas
+    * @pattern [0-9]+
+    * @default "30000000000000"
+    */
+    gas?: string;
+    /** Units in yoctoNear
+    * @default "0"
+    */
+    attachedDeposit?: Balance;
+  }
+  
+}
+export type FundEventStorage__Result = void;
+/**
+* 
+* @contractMethod change
+*/
+export interface AddNearPrize {
+  args: {
+    event_id: EventId;
+    amount: U128;
+    quantity?: u64;
+  };
+  options: {
+    /** Units in gas
+    * @pattern [0-9]+
+    * @default "30000000000000"
+    */
+    gas?: string;
+    /** Units in yoctoNear
+    * @default "0"
+    */
+    attachedDeposit?: Balance;
+  }
+  
+}
+export type AddNearPrize__Result = void;
+/**
+* Adds the pool which is shared among winners by the split
+* 
+* @contractMethod change
+*/
+export interface AddSplitPotPrize {
+  args: {
+    event_id: EventId;
+    amount: U128;
+    split: PrizeSplit;
+  };
+  options: {
+    /** Units in gas
+    * @pattern [0-9]+
+    * @default "30000000000000"
+    */
+    gas?: string;
+    /** Units in yoctoNear
+    * @default "0"
+    */
+    attachedDeposit?: Balance;
+  }
+  
+}
+export type AddSplitPotPrize__Result = void;
+/**
+* Creates the event with prizes & optionally publishes it, the attached deposit
+* should cover prizes with platform fees & storage, the excess is refunded
+* 
+* @contractMethod change
+*/
+export interface CreateEventFull {
+  args: {
+    title: string;
+    start_time?: TimestampMs;
+    end_time?: TimestampMs;
+    metadata?: EventMetadata;
+    prizes: PrizeDefinition[];
+    publish: boolean;
+  };
+  options: {
+    /** Units in gas
+    * @pattern [0-9]+
+    * @default "30000000000000"
+    */
+    gas?: string;
+    /** Units in yoctoNear
+    * @default "0"
+    */
+    attachedDeposit?: Balance;
+  }
+  
+}
+export type CreateEventFull__Result = EventId;
+/**
+* 
+* @contractMethod change
+*/
+export interface CloneEvent {
+  args: {
+    source_event_id: EventId;
+    start_time: TimestampMs;
+    end_time: TimestampMs;
+  };
+  options: {
+    /** Units in gas
+    * @pattern [0-9]+
+    * @default "30000000000000"
+    */
+    gas?: string;
+    /** Units in yoctoNear
+    * @default "0"
+    */
+    attachedDeposit?: Balance;
+  }
+  
+}
+export type CloneEvent__Result = EventId;
+/**
+* 
+* @contractMethod change
+*/
+export interface JoinEvent {
+  args: {
+    event_id: EventId;
+  };
+  options: {
+    /** Units in gas
+    * @pattern [0-9]+
+    * @default "30000000000000"
+    */
+    gas?: string;
+    /** Units in yoctoNear
+    * @default "0"
+    */
+    attachedDeposit?: Balance;
   }
-  is_user_joined_event(args: {
-    account_id: AccountId;
+  
+}
+export type JoinEvent__Result = void;
+/**
+* Bans the account from the event before the draw, the participant is removed
+* with the ticket refunded, banned accounts couldn't join the event
+* 
+* @contractMethod change
+*/
+export interface BanEventParticipant {
+  args: {
     event_id: EventId;
-  }, options?: ViewFunctionOptions): Promise<boolean> {
-    return this.account.viewFunction(this.contractId, "is_user_joined_event", args, options);
-  }
-  async new(args = {}, options?: ChangeMethodOptions): Promise<void> {
-    return providers.getTransactionLastResult(await this.newRaw(args, options));
-  }
-  newRaw(args = {}, options?: ChangeMethodOptions): Promise<providers.FinalExecutionOutcome> {
-    return this.account.functionCall({contractId: this.contractId, methodName: "new", args, ...options});
-  }
-  newTx(args = {}, options?: ChangeMethodOptions): transactions.Action {
-    return transactions.functionCall("new", args, options?.gas ?? DEFAULT_FUNCTION_CALL_GAS, options?.attachedDeposit ?? new BN(0))
+    account_id: AccountId;
+  };
+  options: {
+    /** Units in gas
+    * @pattern [0-9]+
+    * @default "30000000000000"
+    */
+    gas?: string;
+    /** Units in yoctoNear
+    * @default "0"
+    */
+    attachedDeposit?: Balance;
   }
+  
 }
+export type BanEventParticipant__Result = void;
 /**
 * 
 * @contractMethod change
 */
-export interface AddEvent {
+export interface RaffleEventPrizes {
   args: {
-    title: string;
-    start_time?: TimestampMs;
-    end_time?: TimestampMs;
+    event_id: EventId;
   };
   options: {
     /** Units in gas
@@ -293,16 +1658,14 @@ export interface AddEvent {
   }
   
 }
-export type AddEvent__Result = EventId;
+export type RaffleEventPrizes__Result = EventPrize[];
 /**
 * 
 * @contractMethod change
 */
-export interface SetEventTime {
+export interface ClaimPrize {
   args: {
-    event_id: EventId;
-    start_time: TimestampMs;
-    end_time: TimestampMs;
+    prize: EventPrize;
   };
   options: {
     /** Units in gas
@@ -317,14 +1680,15 @@ export interface SetEventTime {
   }
   
 }
-export type SetEventTime__Result = void;
+export type ClaimPrize__Result = void;
 /**
 * 
 * @contractMethod change
 */
-export interface SetEventVisible {
+export interface ArchiveEvent {
   args: {
     event_id: EventId;
+    limit?: u64;
   };
   options: {
     /** Units in gas
@@ -339,15 +1703,15 @@ export interface SetEventVisible {
   }
   
 }
-export type SetEventVisible__Result = void;
+export type ArchiveEvent__Result = boolean;
 /**
 * 
 * @contractMethod change
 */
-export interface AddNearPrize {
+export interface ProposeEventOwner {
   args: {
     event_id: EventId;
-    amount: U128;
+    new_owner_id?: AccountId;
   };
   options: {
     /** Units in gas
@@ -362,12 +1726,12 @@ export interface AddNearPrize {
   }
   
 }
-export type AddNearPrize__Result = void;
+export type ProposeEventOwner__Result = void;
 /**
 * 
 * @contractMethod change
 */
-export interface JoinEvent {
+export interface AcceptEventOwner {
   args: {
     event_id: EventId;
   };
@@ -384,14 +1748,16 @@ export interface JoinEvent {
   }
   
 }
-export type JoinEvent__Result = void;
+export type AcceptEventOwner__Result = void;
 /**
 * 
 * @contractMethod change
 */
-export interface RaffleEventPrizes {
+export interface GrantEventRole {
   args: {
     event_id: EventId;
+    account_id: AccountId;
+    role: EventRole;
   };
   options: {
     /** Units in gas
@@ -406,14 +1772,15 @@ export interface RaffleEventPrizes {
   }
   
 }
-export type RaffleEventPrizes__Result = EventPrize[];
+export type GrantEventRole__Result = void;
 /**
 * 
 * @contractMethod change
 */
-export interface ClaimPrize {
+export interface RevokeEventRole {
   args: {
-    prize: EventPrize;
+    event_id: EventId;
+    account_id: AccountId;
   };
   options: {
     /** Units in gas
@@ -428,7 +1795,7 @@ export interface ClaimPrize {
   }
   
 }
-export type ClaimPrize__Result = void;
+export type RevokeEventRole__Result = void;
 /**
 * 
 * @contractMethod view
@@ -436,11 +1803,11 @@ export type ClaimPrize__Result = void;
 export interface GetOwnerEvents {
   args: {
     account_id: AccountId;
-    pagination?: Pagination;
+    pagination?: CursorPagination<EventId>;
   };
   
 }
-export type GetOwnerEvents__Result = JsonEvent[];
+export type GetOwnerEvents__Result = Page<JsonEvent, EventId>;
 /**
 * 
 * @contractMethod view
@@ -448,11 +1815,24 @@ export type GetOwnerEvents__Result = JsonEvent[];
 export interface GetParticipantEvents {
   args: {
     account_id: AccountId;
-    pagination?: Pagination;
+    pagination?: CursorPagination<EventId>;
+  };
+  
+}
+export type GetParticipantEvents__Result = Page<JsonEvent, EventId>;
+/**
+* 
+* @contractMethod view
+*/
+export interface GetEvents {
+  args: {
+    status?: EventStatus;
+    order_by?: EventOrder;
+    pagination?: CursorPagination<EventId>;
   };
   
 }
-export type GetParticipantEvents__Result = JsonEvent[];
+export type GetEvents__Result = Page<JsonEvent, EventId>;
 /**
 * 
 * @contractMethod view
@@ -471,11 +1851,92 @@ export type GetEvent__Result = JsonEvent | null;
 export interface GetAccountUnclaimedPrizes {
   args: {
     account_id: AccountId;
-    pagination?: Pagination;
+    pagination?: CursorPagination<EventPrize>;
+  };
+  
+}
+export type GetAccountUnclaimedPrizes__Result = Page<EventPrize, EventPrize>;
+/**
+* 
+* @contractMethod view
+*/
+export interface GetEventRoles {
+  args: {
+    event_id: EventId;
+    pagination?: CursorPagination<AccountId>;
+  };
+  
+}
+export type GetEventRoles__Result = Page<JsonEventRole, AccountId>;
+/**
+* 
+* @contractMethod view
+*/
+export interface GetEventSponsoredJoins {
+  args: {
+    event_id: EventId;
+  };
+  
+}
+export type GetEventSponsoredJoins__Result = u64;
+/**
+* 
+* @contractMethod view
+*/
+export interface GetEventWinners {
+  args: {
+    event_id: EventId;
+  };
+  
+}
+export type GetEventWinners__Result = JsonEventWinner[];
+/**
+* 
+* @contractMethod view
+*/
+export interface GetEventParticipants {
+  args: {
+    event_id: EventId;
+    pagination?: CursorPagination<u64>;
+  };
+  
+}
+export type GetEventParticipants__Result = Page<AccountId, u64>;
+/**
+* 
+* @contractMethod view
+*/
+export interface GetEventBannedAccounts {
+  args: {
+    event_id: EventId;
+    pagination?: CursorPagination<u64>;
+  };
+  
+}
+export type GetEventBannedAccounts__Result = Page<AccountId, u64>;
+/**
+* 
+* @contractMethod view
+*/
+export interface GetEventParticipantCount {
+  args: {
+    event_id: EventId;
+  };
+  
+}
+export type GetEventParticipantCount__Result = u64;
+/**
+* 
+* @contractMethod view
+*/
+export interface AreParticipants {
+  args: {
+    event_id: EventId;
+    account_ids: AccountId[];
   };
   
 }
-export type GetAccountUnclaimedPrizes__Result = EventPrize[];
+export type AreParticipants__Result = boolean[];
 /**
 * 
 * @contractMethod view
@@ -492,6 +1953,153 @@ export type IsUserJoinedEvent__Result = boolean;
 * 
 * @contractMethod change
 */
+export interface CreateSeries {
+  args: {
+    title: string;
+    metadata?: EventMetadata;
+    ticket_price?: U128;
+    prizes: U128[];
+    schedule: SeriesSchedule;
+  };
+  options: {
+    /** Units in gas
+    * @pattern [0-9]+
+    * @default "30000000000000"
+    */
+    gas?: string;
+    /** Units in yoctoNear
+    * @default "0"
+    */
+    attachedDeposit?: Balance;
+  }
+  
+}
+export type CreateSeries__Result = SeriesId;
+/**
+* Creates the next event of the series once the previous one has ended, can be called by anyone
+* 
+* @contractMethod change
+*/
+export interface AdvanceSeries {
+  args: {
+    series_id: SeriesId;
+  };
+  options: {
+    /** Units in gas
+    * @pattern [0-9]+
+    * @default "30000000000000"
+    */
+    gas?: string;
+    /** Units in yoctoNear
+    * @default "0"
+    */
+    attachedDeposit?: Balance;
+  }
+  
+}
+export type AdvanceSeries__Result = EventId;
+/**
+* 
+* @contractMethod view
+*/
+export interface GetSeries {
+  args: {
+    series_id: SeriesId;
+  };
+  
+}
+export type GetSeries__Result = JsonSeries | null;
+/**
+* 
+* @contractMethod change
+*/
+export interface StorageDeposit {
+  args: {
+    account_id?: AccountId;
+    registration_only?: boolean;
+  };
+  options: {
+    /** Units in gas
+    * @pattern [0-9]+
+    * @default "30000000000000"
+    */
+    gas?: string;
+    /** Units in yoctoNear
+    * @default "0"
+    */
+    attachedDeposit?: Balance;
+  }
+  
+}
+export type StorageDeposit__Result = StorageBalance;
+/**
+* 
+* @contractMethod change
+*/
+export interface StorageWithdraw {
+  args: {
+    amount?: U128;
+  };
+  options: {
+    /** Units in gas
+    * @pattern [0-9]+
+    * @default "30000000000000"
+    */
+    gas?: string;
+    /** Units in yoctoNear
+    * @default "0"
+    */
+    attachedDeposit?: Balance;
+  }
+  
+}
+export type StorageWithdraw__Result = StorageBalance;
+/**
+* 
+* @contractMethod change
+*/
+export interface StorageUnregister {
+  args: {
+    force?: boolean;
+  };
+  options: {
+    /** Units in gas
+    * @pattern [0-9]+
+    * @default "30000000000000"
+    */
+    gas?: string;
+    /** Units in yoctoNear
+    * @default "0"
+    */
+    attachedDeposit?: Balance;
+  }
+  
+}
+export type StorageUnregister__Result = boolean;
+/**
+* 
+* @contractMethod view
+*/
+export interface StorageBalanceOf {
+  args: {
+    account_id: AccountId;
+  };
+  
+}
+export type StorageBalanceOf__Result = StorageBalance | null;
+/**
+* 
+* @contractMethod view
+*/
+export interface StorageBalanceBounds {
+  args: {};
+  
+}
+export type StorageBalanceBounds__Result = StorageBalanceBounds;
+/**
+* 
+* @contractMethod change
+*/
 export interface New {
   args: {};
   options: {